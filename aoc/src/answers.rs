@@ -0,0 +1,117 @@
+use crate::runner::PartResult;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+// Recorded answers live in one file per day, with the parts separated by a
+// line containing only SEPARATOR so that multi-line answers can be stored.
+const SEPARATOR: &str = "---";
+
+pub fn load(filepath: &str) -> io::Result<Vec<String>> {
+    let contents = fs::read_to_string(filepath)?;
+    let mut answers = vec![String::new()];
+    for line in contents.lines() {
+        if line == SEPARATOR {
+            answers.push(String::new());
+            continue;
+        }
+        let answer = answers.last_mut().unwrap();
+        if !answer.is_empty() {
+            answer.push('\n');
+        }
+        answer.push_str(line);
+    }
+    Ok(answers)
+}
+
+// Records the answer values of the results, leaving a part blank if it has none.
+pub fn save(filepath: &str, results: &[&PartResult]) -> io::Result<()> {
+    let answers: Vec<String> = results.iter()
+        .map(|result| result.answer.as_ref().map(|answer| answer.value.to_string()).unwrap_or_default())
+        .collect();
+    fs::write(filepath, answers.join(&format!("\n{SEPARATOR}\n")) + "\n")
+}
+
+// The recorded answer for a part, or None if there isn't one. A blank part
+// wasn't recorded, e.g. because it failed, so it isn't checked.
+pub fn expected(answers: &[String], part: usize) -> Option<&str> {
+    answers.get(part - 1).map(|answer| answer.as_str()).filter(|answer| !answer.is_empty())
+}
+
+pub fn exists(filepath: &str) -> bool {
+    Path::new(filepath).exists()
+}
+
+// Returns a line-by-line diff of the two answers, or None if they match.
+pub fn diff(expected: &str, actual: &str) -> Option<String> {
+    let expected = expected.trim_end();
+    let actual = actual.trim_end();
+    if expected == actual {
+        return None;
+    }
+
+    let expected_lines: Vec<&str> = expected.lines().collect();
+    let actual_lines: Vec<&str> = actual.lines().collect();
+    let mut diff = Vec::new();
+    for i in 0..expected_lines.len().max(actual_lines.len()) {
+        match (expected_lines.get(i), actual_lines.get(i)) {
+            (Some(e), Some(a)) if e == a => diff.push(format!("  {e}")),
+            (e, a) => {
+                if let Some(e) = e {
+                    diff.push(format!("- {e}"));
+                }
+                if let Some(a) = a {
+                    diff.push(format!("+ {a}"));
+                }
+            }
+        }
+    }
+    Some(diff.join("\n"))
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::problem::Answer;
+    use crate::runner::Status;
+    use std::time::Duration;
+
+    fn result(part: usize, answer: Option<Answer>) -> PartResult {
+        let status = if answer.is_some() { Status::Ok } else { Status::Failed };
+        PartResult {
+            year: 2022, day: 1, part, answer, error: None, diagnostic: None, parse_elapsed: None,
+            elapsed: Duration::ZERO, status
+        }
+    }
+
+    #[test]
+    fn test_expected_skips_blank_parts() {
+        let path = std::env::temp_dir().join(format!("aoc_answers_{}.txt", std::process::id()));
+        let path = path.to_str().unwrap();
+        save(path, &[&result(1, None), &result(2, Some(Answer::integer("Score", 13)))]).unwrap();
+        let answers = load(path).unwrap();
+        fs::remove_file(path).unwrap();
+
+        assert_eq!(expected(&answers, 1), None);
+        assert_eq!(expected(&answers, 2), Some("13"));
+        assert_eq!(expected(&answers[..1], 2), None);
+    }
+
+    #[test]
+    fn test_diff_matching() {
+        assert_eq!(diff("Score: 13", "Score: 13\n"), None);
+    }
+
+    #[test]
+    fn test_diff_mismatch() {
+        let output = diff("Display:\n#..#\n####", "Display:\n#..#\n#..#");
+        assert_eq!(output, Some(String::from("  Display:\n  #..#\n- ####\n+ #..#")));
+    }
+
+    #[test]
+    fn test_diff_missing_lines() {
+        let output = diff("Score: 13", "Score: 13\nextra");
+        assert_eq!(output, Some(String::from("  Score: 13\n+ extra")));
+    }
+}
@@ -0,0 +1,181 @@
+use crate::problem::{Parsed, Solver};
+use crate::runner::run_part;
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::time::Duration;
+
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn new(samples: &[Duration]) -> Stats {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let count = sorted.len() as f64;
+        let nanos: Vec<f64> = sorted.iter().map(|sample| sample.as_nanos() as f64).collect();
+        let mean = nanos.iter().sum::<f64>() / count;
+        let variance = nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / count;
+        let median = if sorted.len() % 2 == 1 {
+            sorted[sorted.len() / 2]
+        } else {
+            (sorted[sorted.len() / 2 - 1] + sorted[sorted.len() / 2]) / 2
+        };
+
+        Stats {
+            min: sorted[0],
+            median,
+            mean: Duration::from_nanos(mean as u64),
+            stddev: Duration::from_nanos(variance.sqrt() as u64),
+        }
+    }
+}
+
+pub struct BenchResult {
+    pub year: usize,
+    pub day: usize,
+    pub part: usize,
+    pub stats: Stats,
+    // Median from the baseline file, when one was given and has this part
+    pub baseline: Option<Duration>,
+}
+
+impl BenchResult {
+    // Relative change of the median against the baseline, in percent.
+    pub fn change(&self) -> Option<f64> {
+        let baseline = self.baseline?.as_nanos() as f64;
+        let median = self.stats.median.as_nanos() as f64;
+        Some((median - baseline) / baseline * 100.0)
+    }
+
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.change().is_some_and(|change| change > threshold)
+    }
+}
+
+// Runs the part `warmup` times without measuring, then `iterations` timed
+// runs, all on the same parsed input. Gives up with the failure as soon as any
+// run fails or panics.
+pub fn bench_part(solver: &dyn Solver, part: usize, parsed: &Parsed, warmup: usize, iterations: usize) -> Result<Stats, String> {
+    for _ in 0..warmup {
+        run_part(solver, part, parsed).0.map_err(|failure| failure.to_string())?;
+    }
+    let mut samples = Vec::new();
+    for _ in 0..iterations {
+        let (answer, elapsed) = run_part(solver, part, parsed);
+        answer.map_err(|failure| failure.to_string())?;
+        samples.push(elapsed);
+    }
+    Ok(Stats::new(&samples))
+}
+
+pub fn print_bench(results: &[BenchResult], threshold: f64) {
+    println!("{:>3} | {:>4} | {:>10} | {:>10} | {:>10} | {:>10} | {:>10}",
+             "Day", "Part", "Min", "Median", "Mean", "Stddev", "Baseline");
+    println!("{:-<3}-+-{:-<4}-+-{:-<10}-+-{:-<10}-+-{:-<10}-+-{:-<10}-+-{:-<10}", "", "", "", "", "", "", "");
+    for result in results {
+        let change = match result.change() {
+            Some(change) if result.is_regression(threshold) => format!("{change:+.1}% !"),
+            Some(change) => format!("{change:+.1}%"),
+            None => String::from("-"),
+        };
+        println!("{:>3} | {:>4} | {:>10} | {:>10} | {:>10} | {:>10} | {:>10}",
+                 result.day, result.part,
+                 format!("{:.2?}", result.stats.min),
+                 format!("{:.2?}", result.stats.median),
+                 format!("{:.2?}", result.stats.mean),
+                 format!("{:.2?}", result.stats.stddev),
+                 change);
+    }
+}
+
+// Baselines are stored as one "year day part median_ns" line per part.
+pub fn save_baseline(filepath: &str, results: &[BenchResult]) -> io::Result<()> {
+    let lines: Vec<String> = results.iter()
+        .map(|result| format!("{} {} {} {}", result.year, result.day, result.part, result.stats.median.as_nanos()))
+        .collect();
+    fs::write(filepath, lines.join("\n") + "\n")
+}
+
+// Medians keyed by (year, day, part). Blank lines are ignored.
+pub fn load_baseline(filepath: &str) -> io::Result<HashMap<(usize, usize, usize), Duration>> {
+    let mut baseline = HashMap::new();
+    for line in fs::read_to_string(filepath)?.lines().filter(|line| !line.trim().is_empty()) {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        let parsed = match fields[..] {
+            [year, day, part, median] => match (year.parse(), day.parse(), part.parse(), median.parse()) {
+                (Ok(year), Ok(day), Ok(part), Ok(median)) => Some(((year, day, part), median)),
+                _ => None,
+            },
+            _ => None,
+        };
+        match parsed {
+            Some((key, median)) => {baseline.insert(key, Duration::from_nanos(median));},
+            None => return Err(io::Error::new(io::ErrorKind::InvalidData, format!("Malformed baseline line: {line}"))),
+        }
+    }
+    Ok(baseline)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|value| Duration::from_millis(*value)).collect()
+    }
+
+    #[test]
+    fn test_stats_odd() {
+        let stats = Stats::new(&millis(&[5, 1, 3]));
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.mean, Duration::from_millis(3));
+    }
+
+    #[test]
+    fn test_stats_even() {
+        let stats = Stats::new(&millis(&[4, 2, 2, 4]));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.stddev, Duration::from_millis(1));
+    }
+
+    #[test]
+    fn test_regression() {
+        let result = BenchResult {
+            year: 2022,
+            day: 1,
+            part: 1,
+            stats: Stats::new(&millis(&[12])),
+            baseline: Some(Duration::from_millis(10)),
+        };
+        assert!(result.is_regression(10.0));
+        assert!(!result.is_regression(25.0));
+    }
+
+    #[test]
+    fn test_baseline_by_year() {
+        let path = std::env::temp_dir().join(format!("aoc_baseline_{}.txt", std::process::id()));
+        let path = path.to_str().unwrap();
+        let results: Vec<BenchResult> = [(2022, 12), (2023, 30)].into_iter()
+            .map(|(year, median)| BenchResult { year, day: 1, part: 1, stats: Stats::new(&millis(&[median])), baseline: None })
+            .collect();
+        save_baseline(path, &results).unwrap();
+        fs::write(path, fs::read_to_string(path).unwrap() + "\n\n").unwrap();
+        let baseline = load_baseline(path).unwrap();
+
+        assert_eq!(baseline.len(), 2);
+        assert_eq!(baseline.get(&(2022, 1, 1)), Some(&Duration::from_millis(12)));
+        assert_eq!(baseline.get(&(2023, 1, 1)), Some(&Duration::from_millis(30)));
+
+        fs::write(path, "1 1 1000\n").unwrap();
+        assert!(load_baseline(path).is_err());
+        fs::remove_file(path).unwrap();
+    }
+}
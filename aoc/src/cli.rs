@@ -0,0 +1,467 @@
+use advent_of_code::{examples, years};
+use advent_of_code::examples::Example;
+use advent_of_code::Level;
+use std::env;
+use std::path::Path;
+use std::str::FromStr;
+use std::time::Duration;
+
+const DEFAULT_INPUTS_DIR: &str = "./src/inputs";
+const DEFAULT_EXAMPLES_DIR: &str = "./examples";
+// Inputs and answers for this year used to sit directly in the inputs
+// directory, before each year had its own
+const FLAT_INPUTS_YEAR: usize = 2022;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+impl Format {
+    fn parse(value: &str) -> Result<Format, String> {
+        match value {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("Unknown format {value}, expected text, json or csv")),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Command {
+    Run,
+    Bench,
+    List,
+    Lint,
+    New,
+    Watch,
+    Repl,
+}
+
+pub struct BenchOptions {
+    pub warmup: usize,
+    pub iterations: usize,
+    // Baseline file to compare medians against
+    pub baseline: Option<String>,
+    // File to write this run's medians to
+    pub save_baseline: Option<String>,
+    // Percentage slowdown over the baseline reported as a regression
+    pub threshold: f64,
+}
+
+pub struct Options {
+    pub command: Command,
+    pub year: usize,
+    pub first_day: usize,
+    pub last_day: usize,
+    // Explicit input file for a single day; "-" reads from stdin.
+    pub input: Option<String>,
+    // Explicit recorded answers file for a single day.
+    pub answers: Option<String>,
+    pub inputs_dir: String,
+    // Run on each day's example, checked against its expected answers
+    pub example: bool,
+    pub examples_dir: String,
+    pub check: bool,
+    pub record: bool,
+    pub format: Format,
+    // Limit on the time each part may run for
+    pub timeout: Option<Duration>,
+    // How often watch checks the inputs for changes
+    pub interval: Duration,
+    // Puzzle parameter overrides, as name and value
+    pub params: Vec<(String, String)>,
+    // Puzzle title for a day created with new
+    pub title: Option<String>,
+    // How much diagnostic output to write to stderr
+    pub log_level: Level,
+    pub bench: BenchOptions,
+}
+
+impl Options {
+    pub fn from_env() -> Result<Options, String> {
+        let args: Vec<String> = env::args().skip(1).collect();
+        let inputs_dir = env::var("AOC_INPUTS_DIR").unwrap_or(String::from(DEFAULT_INPUTS_DIR));
+        Options::parse(&args, inputs_dir)
+    }
+
+    pub fn parse(args: &[String], inputs_dir: String) -> Result<Options, String> {
+        let mut command = Command::Run;
+        let mut year = years::latest();
+        let mut bench = BenchOptions { warmup: 1, iterations: 10, baseline: None, save_baseline: None, threshold: 10.0 };
+        let mut days = None;
+        let mut input = None;
+        let mut answers = None;
+        let mut check = false;
+        let mut record = false;
+        let mut format = Format::Text;
+        let mut timeout = None;
+        let mut interval = Duration::from_millis(500);
+        let mut params = Vec::new();
+        let mut title = None;
+        let mut verbosity = 0;
+        let mut quiet = false;
+        let mut inputs_dir = inputs_dir;
+        let mut example = false;
+        let mut examples_dir = String::from(DEFAULT_EXAMPLES_DIR);
+
+        let mut args = args.iter().peekable();
+        match args.peek().map(|arg| arg.as_str()) {
+            Some("bench") => command = Command::Bench,
+            Some("list") => command = Command::List,
+            Some("lint") => command = Command::Lint,
+            Some("new") => command = Command::New,
+            Some("watch") => command = Command::Watch,
+            Some("repl") => command = Command::Repl,
+            _ => {},
+        }
+        if command != Command::Run {
+            args.next();
+        }
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--warmup" => bench.warmup = Options::number(arg, args.next())?,
+                "--iterations" | "-n" => bench.iterations = Options::number(arg, args.next())?,
+                "--baseline" => bench.baseline = Some(Options::value(arg, args.next())?),
+                "--save-baseline" => bench.save_baseline = Some(Options::value(arg, args.next())?),
+                "--threshold" => bench.threshold = Options::number(arg, args.next())?,
+                "--input" | "-i" => input = Some(Options::value(arg, args.next())?),
+                "--answers" => answers = Some(Options::value(arg, args.next())?),
+                "--example" => example = true,
+                "--examples-dir" => examples_dir = Options::value(arg, args.next())?,
+                "--check" => check = true,
+                "--record" => record = true,
+                "--format" => format = Format::parse(&Options::value(arg, args.next())?)?,
+                "--timeout" => {
+                    let seconds: f64 = Options::number(arg, args.next())?;
+                    if !seconds.is_finite() || seconds <= 0.0 {
+                        return Err(format!("Invalid timeout {seconds}, expected a positive number of seconds"));
+                    }
+                    timeout = Some(Duration::from_secs_f64(seconds));
+                },
+                "--interval" => {
+                    let seconds: f64 = Options::number(arg, args.next())?;
+                    if !seconds.is_finite() || seconds <= 0.0 {
+                        return Err(format!("Invalid interval {seconds}, expected a positive number of seconds"));
+                    }
+                    interval = Duration::from_secs_f64(seconds);
+                },
+                "--param" | "-p" => {
+                    let param = Options::value(arg, args.next())?;
+                    let (name, value) = param.split_once('=')
+                        .ok_or(format!("Invalid parameter {param}, expected name=value"))?;
+                    params.push((String::from(name), String::from(value)));
+                },
+                "--title" => title = Some(Options::value(arg, args.next())?),
+                "--verbose" | "-v" => verbosity += 1,
+                "-vv" => verbosity += 2,
+                "--quiet" | "-q" => quiet = true,
+                "--year" | "-y" => year = Options::number(arg, args.next())?,
+                "--inputs-dir" => inputs_dir = Options::value(arg, args.next())?,
+                _ if days.is_none() => days = Some(arg.clone()),
+                _ => return Err(format!("Unexpected argument {arg}")),
+            }
+        }
+
+        // Listing and linting default to every day, and the repl starts
+        // without one
+        if matches!(command, Command::List | Command::Lint | Command::Repl) && days.is_none() {
+            days = Some(String::from("all"));
+        }
+        let days = days.ok_or("Not enough arguments provided!")?;
+        // New days may start a new year
+        if command != Command::New && years::registry(year).is_none() {
+            return Err(format!("No solutions for year {year}"));
+        }
+        let (first_day, last_day) = parse_days(&days)?;
+        if command == Command::New && first_day != last_day {
+            return Err(String::from("new creates a single day"));
+        }
+        if input.is_some() && first_day != last_day {
+            return Err(String::from("--input can only be used with a single day"));
+        }
+        if answers.is_some() && first_day != last_day {
+            return Err(String::from("--answers can only be used with a single day"));
+        }
+        if command == Command::Watch && input.as_deref() == Some("-") {
+            return Err(String::from("watch cannot read its input from stdin"));
+        }
+        if example && (input.is_some() || answers.is_some()) {
+            return Err(String::from("--example cannot be used with --input or --answers"));
+        }
+        if check && record {
+            return Err(String::from("--check and --record cannot be used together"));
+        }
+        if bench.iterations == 0 {
+            return Err(String::from("--iterations must be at least 1"));
+        }
+        let log_level = match (quiet, verbosity) {
+            (true, 0) => Level::Quiet,
+            (true, _) => return Err(String::from("--quiet and --verbose cannot be used together")),
+            (false, 0) => Level::Normal,
+            (false, 1) => Level::Debug,
+            (false, _) => Level::Trace,
+        };
+        for (name, _) in &params {
+            let declared = (first_day..=last_day)
+                .filter_map(|day| years::get(year, day))
+                .any(|registration| registration.has_param(name));
+            if !declared {
+                return Err(format!("Unknown parameter {name} for the selected days"));
+            }
+        }
+
+        Ok(Options { command, year, first_day, last_day, input, answers, inputs_dir, example, examples_dir, check, record, format, timeout, interval, params, title, log_level, bench })
+    }
+
+    pub fn is_single_day(&self) -> bool {
+        self.first_day == self.last_day
+    }
+
+    // Inputs and answers for each year live in their own subdirectory
+    pub fn year_dir(&self) -> String {
+        format!("{}/{}", self.inputs_dir.trim_end_matches('/'), self.year)
+    }
+
+    pub fn example(&self, day: usize) -> Example {
+        Example::new(&self.examples_dir, self.year, day, examples::DEFAULT_NAME)
+    }
+
+    pub fn input_path(&self, day: usize) -> String {
+        match &self.input {
+            Some(path) => path.clone(),
+            None if self.example => self.example(day).input_path,
+            None => self.inputs_file(&format!("input_{day}.txt")),
+        }
+    }
+
+    pub fn answers_path(&self, day: usize) -> String {
+        match &self.answers {
+            Some(path) => path.clone(),
+            None if self.example => self.example(day).answers_path,
+            None => self.inputs_file(&format!("answers_{day}.txt")),
+        }
+    }
+
+    // A file in the year's inputs directory, falling back to one left in the
+    // old flat layout if that's the only one there
+    fn inputs_file(&self, name: &str) -> String {
+        let path = format!("{}/{name}", self.year_dir());
+        let flat = format!("{}/{name}", self.inputs_dir.trim_end_matches('/'));
+        if self.year == FLAT_INPUTS_YEAR && !Path::new(&path).exists() && Path::new(&flat).exists() {
+            return flat;
+        }
+        path
+    }
+
+    fn value(flag: &str, value: Option<&String>) -> Result<String, String> {
+        value.cloned().ok_or(format!("Missing value for {flag}"))
+    }
+
+    fn number<T: FromStr>(flag: &str, value: Option<&String>) -> Result<T, String> {
+        let value = Options::value(flag, value)?;
+        value.parse().map_err(|_| format!("Invalid number {value} for {flag}"))
+    }
+}
+
+// Accepts a single day ("12"), an inclusive range ("1-10") or "all".
+fn parse_days(arg: &str) -> Result<(usize, usize), String> {
+    let (first, last) = if arg == "all" {
+        (1, 25)
+    } else if let Some((first, last)) = arg.split_once('-') {
+        (parse_day(first)?, parse_day(last)?)
+    } else {
+        let day = parse_day(arg)?;
+        (day, day)
+    };
+
+    if first > last {
+        return Err(format!("Invalid day range {arg}: {first} is after {last}"));
+    }
+    Ok((first, last))
+}
+
+fn parse_day(arg: &str) -> Result<usize, String> {
+    arg.parse::<usize>()
+        .map_err(|e| format!("Unable to cast argument {arg} to usize: {e}"))
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Options, String> {
+        let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+        Options::parse(&args, String::from("inputs"))
+    }
+
+    #[test]
+    fn test_parse_days() {
+        assert_eq!(parse_days("12"), Ok((12, 12)));
+        assert_eq!(parse_days("1-10"), Ok((1, 10)));
+        assert_eq!(parse_days("all"), Ok((1, 25)));
+        assert!(parse_days("10-1").is_err());
+        assert!(parse_days("x").is_err());
+    }
+
+    #[test]
+    fn test_input_paths() {
+        let options = parse(&["3"]).unwrap();
+        assert_eq!(options.input_path(3), "inputs/2022/input_3.txt");
+
+        let options = parse(&["3", "--inputs-dir", "other/"]).unwrap();
+        assert_eq!(options.input_path(3), "other/2022/input_3.txt");
+
+        let options = parse(&["--input", "-", "3"]).unwrap();
+        assert_eq!(options.input_path(3), "-");
+        assert_eq!(options.answers_path(3), "inputs/2022/answers_3.txt");
+    }
+
+    #[test]
+    fn test_flat_inputs_fallback() {
+        let root = std::env::temp_dir().join(format!("aoc_flat_inputs_{}", std::process::id()));
+        let root = root.to_str().unwrap();
+        std::fs::create_dir_all(format!("{root}/2022")).unwrap();
+        std::fs::write(format!("{root}/input_3.txt"), "flat").unwrap();
+        std::fs::write(format!("{root}/input_4.txt"), "flat").unwrap();
+        std::fs::write(format!("{root}/2022/input_4.txt"), "year").unwrap();
+
+        let options = parse(&["3", "--inputs-dir", root]).unwrap();
+        assert_eq!(options.input_path(3), format!("{root}/input_3.txt"));
+        assert_eq!(options.input_path(4), format!("{root}/2022/input_4.txt"));
+        assert_eq!(options.input_path(5), format!("{root}/2022/input_5.txt"));
+        assert_eq!(options.answers_path(3), format!("{root}/2022/answers_3.txt"));
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_example() {
+        let options = parse(&["1-3", "--example"]).unwrap();
+        assert_eq!(options.input_path(3), "./examples/2022/day_3/example.txt");
+        assert_eq!(options.answers_path(3), "./examples/2022/day_3/example.answers.txt");
+
+        let options = parse(&["3", "--example", "--examples-dir", "other"]).unwrap();
+        assert_eq!(options.input_path(3), "other/2022/day_3/example.txt");
+        assert!(parse(&["3", "--example", "--input", "a.txt"]).is_err());
+    }
+
+    #[test]
+    fn test_input_requires_single_day() {
+        assert!(parse(&["1-3", "--input", "a.txt"]).is_err());
+        assert!(parse(&["3", "--input"]).is_err());
+        assert!(parse(&[]).is_err());
+    }
+
+    #[test]
+    fn test_check_and_record_exclusive() {
+        assert!(parse(&["all", "--check"]).unwrap().check);
+        assert!(parse(&["all", "--record"]).unwrap().record);
+        assert!(parse(&["all", "--check", "--record"]).is_err());
+    }
+
+    #[test]
+    fn test_bench() {
+        let options = parse(&["1-3"]).unwrap();
+        assert_eq!(options.command, Command::Run);
+
+        let options = parse(&["bench", "1-3", "-n", "5", "--threshold", "2.5", "--save-baseline", "b.txt"]).unwrap();
+        assert_eq!(options.command, Command::Bench);
+        assert_eq!((options.first_day, options.last_day), (1, 3));
+        assert_eq!(options.bench.iterations, 5);
+        assert_eq!(options.bench.threshold, 2.5);
+        assert_eq!(options.bench.save_baseline, Some(String::from("b.txt")));
+        assert!(parse(&["bench", "1", "-n", "zero"]).is_err());
+        assert!(parse(&["bench", "1", "-n", "0"]).is_err());
+    }
+
+    #[test]
+    fn test_timeout() {
+        assert_eq!(parse(&["all"]).unwrap().timeout, None);
+        assert_eq!(parse(&["all", "--timeout", "1.5"]).unwrap().timeout, Some(Duration::from_millis(1500)));
+        assert!(parse(&["all", "--timeout", "0"]).is_err());
+        assert!(parse(&["all", "--timeout", "soon"]).is_err());
+    }
+
+    #[test]
+    fn test_watch() {
+        let options = parse(&["watch", "3", "--example"]).unwrap();
+        assert_eq!(options.command, Command::Watch);
+        assert_eq!(options.interval, Duration::from_millis(500));
+        assert_eq!(parse(&["watch", "3", "--interval", "2"]).unwrap().interval, Duration::from_secs(2));
+        assert!(parse(&["watch", "3", "--interval", "0"]).is_err());
+        assert!(parse(&["watch", "3", "--input", "-"]).is_err());
+    }
+
+    #[test]
+    fn test_repl() {
+        let options = parse(&["repl"]).unwrap();
+        assert_eq!(options.command, Command::Repl);
+        assert!(!options.is_single_day());
+        assert!(parse(&["repl", "11"]).unwrap().is_single_day());
+    }
+
+    #[test]
+    fn test_list() {
+        let options = parse(&["list"]).unwrap();
+        assert_eq!(options.command, Command::List);
+        assert_eq!((options.first_day, options.last_day), (1, 25));
+
+        let options = parse(&["lint", "3"]).unwrap();
+        assert_eq!(options.command, Command::Lint);
+        assert_eq!((options.first_day, options.last_day), (3, 3));
+    }
+
+    #[test]
+    fn test_new() {
+        let options = parse(&["new", "16", "--title", "Proboscidea Volcanium"]).unwrap();
+        assert_eq!(options.command, Command::New);
+        assert_eq!(options.first_day, 16);
+        assert_eq!(options.title, Some(String::from("Proboscidea Volcanium")));
+        assert_eq!(parse(&["new", "1", "--year", "2023"]).unwrap().year, 2023);
+        assert!(parse(&["new", "1-3"]).is_err());
+        assert!(parse(&["new"]).is_err());
+    }
+
+    #[test]
+    fn test_year() {
+        assert_eq!(parse(&["3"]).unwrap().year, years::latest());
+        assert_eq!(parse(&["3", "--year", "2022"]).unwrap().year, 2022);
+        assert!(parse(&["3", "--year", "1999"]).is_err());
+    }
+
+    #[test]
+    fn test_params() {
+        assert!(parse(&["11"]).unwrap().params.is_empty());
+        let options = parse(&["11", "--param", "part_one_rounds=5", "-p", "part_two_rounds=7"]).unwrap();
+        assert_eq!(options.params, vec![
+            (String::from("part_one_rounds"), String::from("5")),
+            (String::from("part_two_rounds"), String::from("7")),
+        ]);
+        assert!(parse(&["10-12", "--param", "part_one_rounds=5"]).is_ok());
+        assert!(parse(&["12", "--param", "part_one_rounds=5"]).is_err());
+        assert!(parse(&["11", "--param", "part_one_rounds"]).is_err());
+    }
+
+    #[test]
+    fn test_log_level() {
+        assert_eq!(parse(&["all"]).unwrap().log_level, Level::Normal);
+        assert_eq!(parse(&["all", "-q"]).unwrap().log_level, Level::Quiet);
+        assert_eq!(parse(&["all", "-v"]).unwrap().log_level, Level::Debug);
+        assert_eq!(parse(&["all", "-v", "--verbose"]).unwrap().log_level, Level::Trace);
+        assert_eq!(parse(&["all", "-vv"]).unwrap().log_level, Level::Trace);
+        assert!(parse(&["all", "-q", "-v"]).is_err());
+    }
+
+    #[test]
+    fn test_format() {
+        assert_eq!(parse(&["all"]).unwrap().format, Format::Text);
+        assert_eq!(parse(&["all", "--format", "json"]).unwrap().format, Format::Json);
+        assert_eq!(parse(&["all", "--format", "csv"]).unwrap().format, Format::Csv);
+        assert!(parse(&["all", "--format", "xml"]).is_err());
+    }
+}
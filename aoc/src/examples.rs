@@ -0,0 +1,128 @@
+use crate::answers;
+use crate::log::Log;
+use crate::problem::Registration;
+use crate::runner;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+// The worked examples from each puzzle live in DIR/<year>/day_<day>/. Each
+// NAME.txt input has its expected answers alongside in NAME.answers.txt, in
+// the recorded answers format with a part left blank when it isn't checked,
+// and may override the day's parameters in NAME.params.txt with one
+// name=value per line.
+pub const DEFAULT_NAME: &str = "example";
+
+pub struct Example {
+    pub name: String,
+    pub input_path: String,
+    pub answers_path: String,
+    pub params_path: String,
+}
+
+impl Example {
+    pub fn new(dir: &str, year: usize, day: usize, name: &str) -> Example {
+        let base = format!("{}/{name}", day_dir(dir, year, day));
+        Example {
+            name: String::from(name),
+            input_path: format!("{base}.txt"),
+            answers_path: format!("{base}.answers.txt"),
+            params_path: format!("{base}.params.txt"),
+        }
+    }
+
+    // The parameter overrides the example needs, if any
+    pub fn params(&self) -> io::Result<Vec<(String, String)>> {
+        if !Path::new(&self.params_path).exists() {
+            return Ok(Vec::new());
+        }
+        parse_params(&fs::read_to_string(&self.params_path)?)
+    }
+}
+
+pub fn day_dir(dir: &str, year: usize, day: usize) -> String {
+    format!("{}/{year}/day_{day}", dir.trim_end_matches('/'))
+}
+
+// The examples for a day ordered by name, or none if it has no directory
+pub fn find(dir: &str, year: usize, day: usize) -> io::Result<Vec<Example>> {
+    let day_dir = day_dir(dir, year, day);
+    if !Path::new(&day_dir).is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut names = Vec::new();
+    for entry in fs::read_dir(&day_dir)? {
+        let file_name = entry?.file_name().to_string_lossy().into_owned();
+        if let Some(name) = file_name.strip_suffix(".txt") {
+            if !name.ends_with(".answers") && !name.ends_with(".params") {
+                names.push(String::from(name));
+            }
+        }
+    }
+    names.sort();
+    Ok(names.iter().map(|name| Example::new(dir, year, day, name)).collect())
+}
+
+// Runs each implemented part that has an expected answer on the example,
+// returning a description of every part that didn't produce it.
+pub fn check(registration: &Registration, example: &Example) -> io::Result<Vec<String>> {
+    let input = runner::read_input(&example.input_path)?;
+    let expected = answers::load(&example.answers_path)?;
+    let solver = registration.problem_with(&example.params()?, &Log::default());
+
+    let parsed = match runner::run_parse(solver.as_ref(), &input).0 {
+        Ok(parsed) => parsed,
+        Err(failure) => return Ok(vec![format!("parse: {failure}")]),
+    };
+    let mut failures = Vec::new();
+    for part in registration.parts.iter().copied() {
+        let expected = match answers::expected(&expected, part) {
+            Some(expected) => expected,
+            None => continue,
+        };
+        match runner::run_part(solver.as_ref(), part, parsed.as_ref()).0 {
+            Ok(answer) => {
+                if let Some(diff) = answers::diff(expected, &answer.value.to_string()) {
+                    failures.push(format!("part {part}: MISMATCH\n{diff}"));
+                }
+            },
+            Err(failure) => failures.push(format!("part {part}: {failure}")),
+        }
+    }
+    Ok(failures)
+}
+
+fn parse_params(contents: &str) -> io::Result<Vec<(String, String)>> {
+    let mut params = Vec::new();
+    for line in contents.lines().map(|line| line.trim()).filter(|line| !line.is_empty()) {
+        let (name, value) = line.split_once('=')
+            .ok_or(io::Error::new(io::ErrorKind::InvalidData, format!("invalid parameter {line}, expected name=value")))?;
+        params.push((String::from(name.trim()), String::from(value.trim())));
+    }
+    Ok(params)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paths() {
+        let example = Example::new("examples/", 2022, 9, "larger");
+        assert_eq!(example.input_path, "examples/2022/day_9/larger.txt");
+        assert_eq!(example.answers_path, "examples/2022/day_9/larger.answers.txt");
+        assert_eq!(example.params_path, "examples/2022/day_9/larger.params.txt");
+    }
+
+    #[test]
+    fn test_parse_params() {
+        let params = parse_params("line_to_search=10\n\nextent = 20\n").unwrap();
+        assert_eq!(params, vec![
+            (String::from("line_to_search"), String::from("10")),
+            (String::from("extent"), String::from("20")),
+        ]);
+        assert!(parse_params("extent").is_err());
+    }
+}
@@ -1,6 +1,8 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
 use std::collections::VecDeque;
-use std::collections::HashSet;
 
+#[derive(Clone)]
 pub struct Graph {
     nodes: Vec<NodeData>,
     edges: Vec<EdgeData>,
@@ -8,16 +10,17 @@ pub struct Graph {
 
 pub type NodeIndex = usize;
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct NodeData {
     first_outgoing_edge: Option<EdgeIndex>,
-    shortest_path: Option<u32>
 }
 
 pub type EdgeIndex = usize;
 
+#[derive(Clone)]
 pub struct EdgeData {
     target: NodeIndex,
+    weight: u32,
     next_outgoing_edge: Option<EdgeIndex>
 }
 
@@ -31,95 +34,451 @@ impl Graph {
 
     pub fn add_node(&mut self) -> NodeIndex {
         let index = self.nodes.len();
-        self.nodes.push(NodeData { first_outgoing_edge: None, 
-                                   shortest_path: None});
+        self.nodes.push(NodeData { first_outgoing_edge: None });
         index
     }
 
     pub fn add_edge(&mut self, source: NodeIndex, target: NodeIndex) {
+        self.add_weighted_edge(source, target, 1);
+    }
+
+    pub fn add_weighted_edge(&mut self, source: NodeIndex, target: NodeIndex, weight: u32) {
         let edge_index = self.edges.len();
         let node_data = &mut self.nodes[source];
         self.edges.push(EdgeData {
             target: target,
+            weight,
             next_outgoing_edge: node_data.first_outgoing_edge
         });
         node_data.first_outgoing_edge = Some(edge_index);
     }
 
-    pub fn successors(&self, source: NodeIndex) -> Successors {
+    pub fn successors(&self, source: NodeIndex) -> Successors<'_> {
+        Successors { edges: self.outgoing_edges(source) }
+    }
+
+    // Successors along with the weight of the edge to each
+    pub fn weighted_successors(&self, source: NodeIndex) -> WeightedSuccessors<'_> {
+        WeightedSuccessors { edges: self.outgoing_edges(source) }
+    }
+
+    fn outgoing_edges(&self, source: NodeIndex) -> OutgoingEdges<'_> {
         let first_outgoing_edge = self.nodes[source].first_outgoing_edge;
-        Successors { graph: self, current_edge_index: first_outgoing_edge }
-    }
-
-    pub fn shortest_path(&mut self, start: NodeIndex, end: NodeIndex) -> u32 {
-        // Implements Dijkstra's shortest path algorithm. 
-        // Currently assumes weightings are all 1.
-        let mut queue: VecDeque<(NodeIndex, u32)> = VecDeque::from([(start, 0)]);
-
-        loop {
-            let (current, current_distance) = match queue.pop_front() {
-                None => {break;},
-                Some(x) => x,
-            };
-
-            let node_data = &mut self.nodes[current];
-            let update_current = match node_data.shortest_path {
-                None => true, 
-                Some(x) => x > current_distance
-            };
-            if update_current {
-                node_data.shortest_path = Some(current_distance);
-                for successor_node in self.successors(current) {
-                    queue.push_back((successor_node, current_distance + 1)); // ToDo: include weights
+        OutgoingEdges { graph: self, current_edge_index: first_outgoing_edge }
+    }
+
+    pub fn shortest_path(&self, start: NodeIndex, end: NodeIndex) -> Option<u32> {
+        self.dijkstra(start, Some(end)).distance(end)
+    }
+
+    pub fn shortest_route(&self, start: NodeIndex, end: NodeIndex) -> Option<Route> {
+        self.dijkstra(start, Some(end)).route(end)
+    }
+
+    // Weighted distances from start to every node it can reach
+    pub fn shortest_paths_from(&self, start: NodeIndex) -> Distances {
+        self.dijkstra(start, None)
+    }
+
+    // Searches towards end guided by heuristic, an estimate of each node's
+    // remaining distance to end. The route found is shortest as long as the
+    // estimate never exceeds the true distance.
+    pub fn astar(&self, start: NodeIndex, end: NodeIndex, heuristic: impl Fn(NodeIndex) -> u32) -> Distances {
+        let mut distances = Distances::new(self.nodes.len(), &[start]);
+        // Ordered by estimated total, then by estimate remaining so ties go
+        // to whichever is closer to end
+        let mut queue: BinaryHeap<Reverse<(u32, u32, u32, NodeIndex)>> = BinaryHeap::from([Reverse((heuristic(start), heuristic(start), 0, start))]);
+
+        while let Some(Reverse((_, _, current_distance, current))) = queue.pop() {
+            if current == end {
+                break;
+            }
+            // Skip entries superseded by a shorter path found since
+            if distances.distance(current).is_some_and(|x| x < current_distance) {
+                continue;
+            }
+
+            distances.expanded += 1;
+            for (successor_node, weight) in self.weighted_successors(current) {
+                let distance = current_distance + weight;
+                let improves = match distances.distance(successor_node) {
+                    None => true,
+                    Some(x) => distance < x
+                };
+                if improves {
+                    distances.reach(successor_node, distance, current);
+                    let estimate = heuristic(successor_node);
+                    queue.push(Reverse((distance + estimate, estimate, distance, successor_node)));
                 }
             }
         }
-        
-        let target_node = &mut self.nodes[end];
-        return target_node.shortest_path.unwrap();
+        distances
+    }
+
+    // The search bfs and bfs_route run, for seeing how much of the graph it
+    // expanded before reaching end
+    pub fn bfs_search(&self, start: NodeIndex, end: NodeIndex) -> Distances {
+        self.breadth_first(&[start], Some(end))
     }
 
-    pub fn bfs(&mut self, start: NodeIndex, end: NodeIndex) -> Option<u32> {
-        // Implements Breadth First Search algorithm. 
-        let mut queue: VecDeque<(NodeIndex, u32)> = VecDeque::from([(start, 0)]);
-        let mut visited = HashSet::from([start]);
+    pub fn bfs(&self, start: NodeIndex, end: NodeIndex) -> Option<u32> {
+        self.breadth_first(&[start], Some(end)).distance(end)
+    }
 
-        loop {
-            let (current, current_distance) = match queue.pop_front() {
-                None => {return None;},
-                Some(x) => x,
-            };
+    pub fn bfs_route(&self, start: NodeIndex, end: NodeIndex) -> Option<Route> {
+        self.breadth_first(&[start], Some(end)).route(end)
+    }
 
-            if current == end {
-                return Some(current_distance)
+    // Edge counts from start to every node it can reach
+    pub fn bfs_from(&self, start: NodeIndex) -> Distances {
+        self.breadth_first(&[start], None)
+    }
+
+    // The route to end from whichever of starts is nearest, found in a single
+    // search rather than one per start
+    pub fn bfs_route_from_any(&self, starts: &[NodeIndex], end: NodeIndex) -> Option<Route> {
+        self.breadth_first(starts, Some(end)).route(end)
+    }
+
+    // Edge counts to every reachable node from the nearest of starts
+    pub fn bfs_from_any(&self, starts: &[NodeIndex]) -> Distances {
+        self.breadth_first(starts, None)
+    }
+
+    // The same nodes with every edge turned around, keeping its weight, so a
+    // search from a node finds the distances to it
+    pub fn reversed(&self) -> Graph {
+        let mut reversed = Graph::new();
+        for _ in &self.nodes {
+            reversed.add_node();
+        }
+        for source in 0..self.nodes.len() {
+            for (target, weight) in self.weighted_successors(source) {
+                reversed.add_weighted_edge(target, source, weight);
             }
+        }
+        reversed
+    }
+
+    fn dijkstra(&self, start: NodeIndex, end: Option<NodeIndex>) -> Distances {
+        // Implements Dijkstra's shortest path algorithm, always expanding the
+        // closest unsettled node next. Stops early once end is settled.
+        let mut distances = Distances::new(self.nodes.len(), &[start]);
+        let mut queue: BinaryHeap<Reverse<(u32, NodeIndex)>> = BinaryHeap::from([Reverse((0, start))]);
+
+        while let Some(Reverse((current_distance, current))) = queue.pop() {
+            if Some(current) == end {
+                break;
+            }
+            // Skip entries superseded by a shorter path found since
+            if distances.distance(current).is_some_and(|x| x < current_distance) {
+                continue;
+            }
+
+            distances.expanded += 1;
+            for (successor_node, weight) in self.weighted_successors(current) {
+                let distance = current_distance + weight;
+                let improves = match distances.distance(successor_node) {
+                    None => true,
+                    Some(x) => distance < x
+                };
+                if improves {
+                    distances.reach(successor_node, distance, current);
+                    queue.push(Reverse((distance, successor_node)));
+                }
+            }
+        }
+        distances
+    }
+
+    fn breadth_first(&self, starts: &[NodeIndex], end: Option<NodeIndex>) -> Distances {
+        // Implements Breadth First Search algorithm, seeding the queue with
+        // every start at distance 0.
+        let mut distances = Distances::new(self.nodes.len(), starts);
+        let mut queue: VecDeque<(NodeIndex, u32)> = starts.iter().map(|start| (*start, 0)).collect();
 
+        while let Some((current, current_distance)) = queue.pop_front() {
+            if Some(current) == end {
+                break;
+            }
+
+            distances.expanded += 1;
             for successor_node in self.successors(current) {
-                if ! visited.contains(&successor_node) {
-                    visited.insert(successor_node);
+                if distances.distance(successor_node).is_none() {
+                    distances.reach(successor_node, current_distance + 1, current);
                     queue.push_back((successor_node, current_distance + 1));
                 }
             }
         }
+        distances
     }
 }
 
-pub struct Successors<'graph> {
+// The result of a search: how far each node is from the nearest start, and
+// the node each was reached from. Nodes the search didn't reach have no
+// distance.
+#[derive(Clone, Debug)]
+pub struct Distances {
+    distances: Vec<Option<u32>>,
+    predecessors: Vec<Option<NodeIndex>>,
+    expanded: usize,
+}
+
+impl Distances {
+    fn new(size: usize, starts: &[NodeIndex]) -> Distances {
+        let mut distances = vec![None; size];
+        for start in starts {
+            distances[*start] = Some(0);
+        }
+        Distances { distances, predecessors: vec![None; size], expanded: 0 }
+    }
+
+    fn reach(&mut self, node: NodeIndex, distance: u32, predecessor: NodeIndex) {
+        self.distances[node] = Some(distance);
+        self.predecessors[node] = Some(predecessor);
+    }
+
+    pub fn distance(&self, node: NodeIndex) -> Option<u32> {
+        self.distances[node]
+    }
+
+    pub fn route(&self, end: NodeIndex) -> Option<Route> {
+        let distance = self.distance(end)?;
+        let mut nodes = vec![end];
+        // Walk back through each node's predecessor until reaching a start,
+        // which has none
+        while let Some(previous) = self.predecessors[*nodes.last().unwrap()] {
+            nodes.push(previous);
+        }
+        nodes.reverse();
+        Some(Route { distance, nodes })
+    }
+
+    // How many nodes the search took successors from
+    pub fn expanded(&self) -> usize {
+        self.expanded
+    }
+
+    // The reached nodes and their distances, in node order
+    pub fn reached(&self) -> impl Iterator<Item = (NodeIndex, u32)> + '_ {
+        self.distances.iter().enumerate().filter_map(|(node, distance)| distance.map(|distance| (node, distance)))
+    }
+}
+
+// A path found by a search, with the nodes visited from start to end inclusive
+#[derive(Clone, Debug, PartialEq)]
+pub struct Route {
+    pub distance: u32,
+    pub nodes: Vec<NodeIndex>,
+}
+
+struct OutgoingEdges<'graph> {
     graph: &'graph Graph,
     current_edge_index: Option<EdgeIndex>,
 }
 
-impl<'graph> Iterator for Successors<'graph> {
-    type Item = NodeIndex;
-    
-    fn next(&mut self) -> Option<NodeIndex> {
+impl<'graph> Iterator for OutgoingEdges<'graph> {
+    type Item = &'graph EdgeData;
+
+    fn next(&mut self) -> Option<&'graph EdgeData> {
         match self.current_edge_index {
             None => None,
             Some(edge_num) => {
                 let edge = &self.graph.edges[edge_num];
                 self.current_edge_index = edge.next_outgoing_edge;
-                Some(edge.target)
+                Some(edge)
             }
         }
     }
-}
\ No newline at end of file
+}
+
+pub struct Successors<'graph> {
+    edges: OutgoingEdges<'graph>,
+}
+
+impl<'graph> Iterator for Successors<'graph> {
+    type Item = NodeIndex;
+    
+    fn next(&mut self) -> Option<NodeIndex> {
+        self.edges.next().map(|edge| edge.target)
+    }
+}
+
+pub struct WeightedSuccessors<'graph> {
+    edges: OutgoingEdges<'graph>,
+}
+
+impl<'graph> Iterator for WeightedSuccessors<'graph> {
+    type Item = (NodeIndex, u32);
+
+    fn next(&mut self) -> Option<(NodeIndex, u32)> {
+        self.edges.next().map(|edge| (edge.target, edge.weight))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a -> b -> c -> d costs 3, cheaper than the direct a -> d edge
+    fn weighted_graph() -> (Graph, [NodeIndex; 5]) {
+        let mut graph = Graph::new();
+        let nodes = [graph.add_node(), graph.add_node(), graph.add_node(), graph.add_node(), graph.add_node()];
+        let [a, b, c, d, _] = nodes;
+        graph.add_weighted_edge(a, d, 10);
+        graph.add_weighted_edge(a, b, 1);
+        graph.add_weighted_edge(b, c, 1);
+        graph.add_weighted_edge(c, d, 1);
+        graph.add_weighted_edge(a, c, 5);
+        (graph, nodes)
+    }
+
+    #[test]
+    fn test_shortest_path_weighted() {
+        let (graph, [a, b, c, d, _]) = weighted_graph();
+        assert_eq!(graph.shortest_path(a, a), Some(0));
+        assert_eq!(graph.shortest_path(a, b), Some(1));
+        assert_eq!(graph.shortest_path(a, c), Some(2));
+        assert_eq!(graph.shortest_path(a, d), Some(3));
+        assert_eq!(graph.shortest_path(b, d), Some(2));
+    }
+
+    #[test]
+    fn test_shortest_path_unreachable() {
+        let (graph, [a, _, _, d, e]) = weighted_graph();
+        assert_eq!(graph.shortest_path(a, e), None);
+        assert_eq!(graph.shortest_path(d, a), None);
+        // Earlier searches don't affect later ones
+        assert_eq!(graph.shortest_path(a, d), Some(3));
+    }
+
+    #[test]
+    fn test_shortest_path_zero_weights() {
+        let mut graph = Graph::new();
+        let a = graph.add_node();
+        let b = graph.add_node();
+        let c = graph.add_node();
+        graph.add_weighted_edge(a, b, 0);
+        graph.add_weighted_edge(b, c, 4);
+        graph.add_weighted_edge(a, c, 5);
+        assert_eq!(graph.shortest_path(a, c), Some(4));
+    }
+
+    #[test]
+    fn test_unweighted_edges() {
+        let mut graph = Graph::new();
+        let a = graph.add_node();
+        let b = graph.add_node();
+        let c = graph.add_node();
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+        assert_eq!(graph.weighted_successors(a).collect::<Vec<_>>(), vec![(b, 1)]);
+        assert_eq!(graph.shortest_path(a, c), graph.bfs(a, c));
+    }
+
+    #[test]
+    fn test_shortest_route() {
+        let (graph, [a, b, c, d, e]) = weighted_graph();
+        assert_eq!(graph.shortest_route(a, d), Some(Route { distance: 3, nodes: vec![a, b, c, d] }));
+        assert_eq!(graph.shortest_route(b, b), Some(Route { distance: 0, nodes: vec![b] }));
+        assert_eq!(graph.shortest_route(a, e), None);
+    }
+
+    #[test]
+    fn test_bfs_route() {
+        // Fewest edges wins regardless of weight
+        let (graph, [a, _, c, d, e]) = weighted_graph();
+        assert_eq!(graph.bfs_route(a, d), Some(Route { distance: 1, nodes: vec![a, d] }));
+        assert_eq!(graph.bfs_route(a, c).unwrap().nodes.len(), 2);
+        assert_eq!(graph.bfs_route(d, e), None);
+    }
+
+    #[test]
+    fn test_distances_from() {
+        let (graph, [a, b, c, d, e]) = weighted_graph();
+        let distances = graph.shortest_paths_from(a);
+        assert_eq!(distances.reached().collect::<Vec<_>>(), vec![(a, 0), (b, 1), (c, 2), (d, 3)]);
+        assert_eq!(distances.route(d).map(|route| route.nodes), Some(vec![a, b, c, d]));
+        assert_eq!(distances.distance(e), None);
+        assert_eq!(graph.bfs_from(a).distance(d), Some(1));
+        assert_eq!(graph.bfs_from(b).distance(a), None);
+    }
+
+    #[test]
+    fn test_shared_between_threads() {
+        let (graph, [a, b, c, d, _]) = weighted_graph();
+        let graph = &graph;
+        let distances: Vec<Option<u32>> = std::thread::scope(|scope| {
+            let handles: Vec<_> = [a, b, c, d].into_iter()
+                .map(|start| scope.spawn(move || graph.shortest_path(start, d)))
+                .collect();
+            handles.into_iter().map(|handle| handle.join().unwrap()).collect()
+        });
+        assert_eq!(distances, vec![Some(3), Some(2), Some(1), Some(0)]);
+    }
+
+    #[test]
+    fn test_bfs_from_any() {
+        let (graph, [a, b, c, d, e]) = weighted_graph();
+        assert_eq!(graph.bfs_route_from_any(&[b, e], d), Some(Route { distance: 2, nodes: vec![b, c, d] }));
+        assert_eq!(graph.bfs_route_from_any(&[b, c], d), Some(Route { distance: 1, nodes: vec![c, d] }));
+        assert_eq!(graph.bfs_route_from_any(&[e], d), None);
+        assert_eq!(graph.bfs_from_any(&[a, e]).reached().collect::<Vec<_>>(), vec![(a, 0), (b, 1), (c, 1), (d, 1), (e, 0)]);
+    }
+
+    #[test]
+    fn test_reversed() {
+        let (graph, [a, b, c, d, e]) = weighted_graph();
+        let reversed = graph.reversed();
+        assert_eq!(reversed.shortest_route(d, a), Some(Route { distance: 3, nodes: vec![d, c, b, a] }));
+        assert_eq!(reversed.shortest_path(a, d), None);
+        let to_d = reversed.shortest_paths_from(d);
+        for node in [a, b, c, d, e] {
+            assert_eq!(to_d.distance(node), graph.shortest_path(node, d));
+        }
+    }
+
+    #[test]
+    fn test_astar() {
+        let (graph, [a, b, c, d, e]) = weighted_graph();
+        // Remaining cost along the cheap chain, which never overestimates
+        let heuristic = |node| [3, 2, 1, 0, 0][node];
+        let search = graph.astar(a, d, heuristic);
+        assert_eq!(search.route(d), Some(Route { distance: 3, nodes: vec![a, b, c, d] }));
+        assert_eq!(search.expanded(), 3);
+        assert_eq!(graph.astar(a, e, heuristic).route(e), None);
+        // Without a heuristic it matches Dijkstra
+        assert_eq!(graph.astar(b, d, |_| 0).distance(d), graph.shortest_path(b, d));
+    }
+
+    #[test]
+    fn test_astar_on_grid() {
+        // An open 10x10 grid, searched corner to corner
+        let mut graph = Graph::new();
+        let size = 10;
+        let nodes: Vec<NodeIndex> = (0..size * size).map(|_| graph.add_node()).collect();
+        for row in 0..size {
+            for column in 0..size {
+                let node = nodes[row * size + column];
+                if row + 1 < size {
+                    graph.add_edge(node, nodes[(row + 1) * size + column]);
+                    graph.add_edge(nodes[(row + 1) * size + column], node);
+                }
+                if column + 1 < size {
+                    graph.add_edge(node, nodes[row * size + column + 1]);
+                    graph.add_edge(nodes[row * size + column + 1], node);
+                }
+            }
+        }
+        let (start, end) = (nodes[0], nodes[size * size - 1]);
+        let manhattan = |node: NodeIndex| ((size - 1 - node / size) + (size - 1 - node % size)) as u32;
+
+        let astar = graph.astar(start, end, manhattan);
+        let bfs = graph.bfs_search(start, end);
+        assert_eq!(astar.distance(end), Some(18));
+        assert_eq!(bfs.distance(end), Some(18));
+        assert_eq!(astar.route(end).unwrap().nodes.len(), 19);
+        assert!(astar.expanded() < bfs.expanded());
+    }
+}
@@ -0,0 +1,106 @@
+use std::fmt;
+
+// Puts puzzle input into the shape every solver can rely on: "\n" line
+// endings, no trailing whitespace on any line, and no blank lines or newline
+// at the end. Leading whitespace is significant to some puzzles (day 5's
+// crates, day 22's map) and is kept.
+pub fn normalise(input: &str) -> String {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let input = input.replace("\r\n", "\n").replace('\r', "\n");
+    let lines: Vec<&str> = input.split('\n').map(|line| line.trim_end()).collect();
+    let end = lines.iter().rposition(|line| !line.is_empty()).map_or(0, |last| last + 1);
+    lines[..end].join("\n")
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LintKind {
+    CrLf,
+    Tab,
+    NonAscii(char),
+}
+
+impl fmt::Display for LintKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LintKind::CrLf => write!(f, "Windows (CRLF) line ending"),
+            LintKind::Tab => write!(f, "tab character"),
+            LintKind::NonAscii(c) => write!(f, "non-ASCII character {c:?}"),
+        }
+    }
+}
+
+// Something in an input that normalising may hide or a solver may trip over.
+// Each kind is reported once, at its first occurrence, with how many times it
+// occurs. Lines and columns count from 1.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Lint {
+    pub kind: LintKind,
+    pub line: usize,
+    pub column: usize,
+    pub count: usize,
+}
+
+impl fmt::Display for Lint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}, column {}: {}", self.line, self.column, self.kind)?;
+        if self.count > 1 {
+            write!(f, " ({} in total)", self.count)?;
+        }
+        Ok(())
+    }
+}
+
+pub fn lint(input: &str) -> Vec<Lint> {
+    let mut lints: Vec<Lint> = Vec::new();
+    let mut report = |kind: LintKind, line: usize, column: usize| {
+        let same_kind = |lint: &&mut Lint| match (lint.kind, kind) {
+            (LintKind::NonAscii(_), LintKind::NonAscii(_)) => true,
+            (a, b) => a == b,
+        };
+        match lints.iter_mut().find(same_kind) {
+            Some(lint) => lint.count += 1,
+            None => lints.push(Lint { kind, line, column, count: 1 }),
+        }
+    };
+
+    for (i, line) in input.split('\n').enumerate() {
+        let mut chars = line.chars().enumerate().peekable();
+        while let Some((column, c)) = chars.next() {
+            match c {
+                '\r' if chars.peek().is_none() => report(LintKind::CrLf, i + 1, column + 1),
+                '\t' => report(LintKind::Tab, i + 1, column + 1),
+                c if !c.is_ascii() => report(LintKind::NonAscii(c), i + 1, column + 1),
+                _ => {},
+            }
+        }
+    }
+    lints
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalise() {
+        assert_eq!(normalise("1000\r\n2000\r\n\r\n3000\r\n"), "1000\n2000\n\n3000");
+        assert_eq!(normalise("mjqjpqm\n\n\n"), "mjqjpqm");
+        assert_eq!(normalise("    [D]    \n[N] [C]   \n"), "    [D]\n[N] [C]");
+        assert_eq!(normalise("\u{feff}2-4,6-8 \n  \n"), "2-4,6-8");
+        assert_eq!(normalise("\n\n"), "");
+    }
+
+    #[test]
+    fn test_lint() {
+        assert!(lint("1000\n2000\n").is_empty());
+        let lints = lint("1\t2\r\n3\r\né4\r\n");
+        assert_eq!(lints, vec![
+            Lint { kind: LintKind::Tab, line: 1, column: 2, count: 1 },
+            Lint { kind: LintKind::CrLf, line: 1, column: 4, count: 3 },
+            Lint { kind: LintKind::NonAscii('é'), line: 3, column: 1, count: 1 },
+        ]);
+        assert_eq!(lints[1].to_string(), "line 1, column 4: Windows (CRLF) line ending (3 in total)");
+        assert_eq!(lints[2].to_string(), "line 3, column 1: non-ASCII character 'é'");
+    }
+}
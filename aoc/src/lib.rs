@@ -0,0 +1,17 @@
+pub mod answers;
+pub mod bench;
+pub mod examples;
+pub mod graph;
+pub mod input;
+pub mod log;
+pub mod parse;
+pub mod problem;
+pub mod runner;
+pub mod years;
+
+#[macro_use]
+extern crate lazy_static;
+
+pub use crate::graph::{Distances, Graph, Route};
+pub use crate::log::{Level, Log};
+pub use crate::problem::{Param, Params, Problem, ProblemError, Registration, Solver};
@@ -0,0 +1,106 @@
+use std::sync::{Arc, Mutex};
+
+// How much diagnostic output to show, selected with -q and -v
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    // Nothing but errors
+    Quiet,
+    // Notices from the runner, such as days skipped for lack of input
+    #[default]
+    Normal,
+    // Intermediate results from solvers
+    Debug,
+    // Step by step detail from solvers
+    Trace,
+}
+
+impl Level {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Level::Quiet => "quiet",
+            Level::Normal => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        }
+    }
+}
+
+// Writes diagnostics to stderr so they never mix with the answers on stdout.
+// Messages are only built when their level is enabled, so solvers can log
+// freely without slowing down timed runs.
+#[derive(Clone, Debug, Default)]
+pub struct Log {
+    level: Level,
+    // Collects messages instead of printing them, for tests
+    buffer: Option<Arc<Mutex<Vec<String>>>>,
+}
+
+impl Log {
+    pub fn new(level: Level) -> Log {
+        Log { level, buffer: None }
+    }
+
+    pub fn buffered(level: Level) -> Log {
+        Log { level, buffer: Some(Arc::new(Mutex::new(Vec::new()))) }
+    }
+
+    pub fn enabled(&self, level: Level) -> bool {
+        level != Level::Quiet && level <= self.level
+    }
+
+    pub fn info(&self, message: impl FnOnce() -> String) {
+        self.write(Level::Normal, message);
+    }
+
+    pub fn debug(&self, message: impl FnOnce() -> String) {
+        self.write(Level::Debug, message);
+    }
+
+    pub fn trace(&self, message: impl FnOnce() -> String) {
+        self.write(Level::Trace, message);
+    }
+
+    // Messages collected by a buffered log, as "level: message"
+    pub fn messages(&self) -> Vec<String> {
+        match &self.buffer {
+            Some(buffer) => buffer.lock().unwrap().clone(),
+            None => Vec::new(),
+        }
+    }
+
+    fn write(&self, level: Level, message: impl FnOnce() -> String) {
+        if !self.enabled(level) {
+            return;
+        }
+        let line = format!("{}: {}", level.name(), message());
+        match &self.buffer {
+            Some(buffer) => buffer.lock().unwrap().push(line),
+            None => eprintln!("{line}"),
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_levels() {
+        let log = Log::buffered(Level::Debug);
+        log.info(|| String::from("skipped"));
+        log.debug(|| String::from("found 3"));
+        log.trace(|| panic!("trace messages shouldn't be built"));
+        assert_eq!(log.messages(), vec!["info: skipped", "debug: found 3"]);
+    }
+
+    #[test]
+    fn test_quiet() {
+        let log = Log::buffered(Level::Quiet);
+        log.info(|| String::from("skipped"));
+        assert!(log.messages().is_empty());
+        assert!(!log.enabled(Level::Quiet));
+        assert!(Log::default().enabled(Level::Normal));
+        assert!(!Log::default().enabled(Level::Debug));
+    }
+}
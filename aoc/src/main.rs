@@ -1,92 +1,314 @@
-pub mod days;
-pub mod problem;
-pub mod graph;
+mod cli;
+mod repl;
+mod scaffold;
+mod watch;
 
-#[macro_use]
-extern crate lazy_static;
-
-use crate::problem::Problem;
-use crate::days::*;
-use std::fs;
-use std::env;
-use std::time::Instant;
+use advent_of_code::{answers, bench, input, runner, years};
+use advent_of_code::{Log, Registration};
+use advent_of_code::bench::BenchResult;
+use advent_of_code::runner::{PartResult, Status, run_day, print_table, print_json, print_csv, print_diagnostics};
+use crate::cli::{Command, Format, Options};
+use std::collections::HashMap;
+use std::io;
+use std::path::Path;
+use std::process;
+use std::sync::Arc;
 
 
 fn main() {
-    let day = get_day().unwrap();
-
-    let input_name = format!("./src/inputs/input_{}.txt", day);
-    let input = read_file(input_name);
-    let problem = day_to_problem(day).unwrap();
-    let now = Instant::now();
-    let part_1 = problem.part_one(&input);
-    println!("Part 1 answer: {}", part_1);
-    let elapsed = now.elapsed();
-    println!("Elapsed: {:.2?}", elapsed);
-    
-    let now = Instant::now();
-    let part_2 = problem.part_two(&input);
-    println!("Part 2 answer: {}", part_2);
-    let elapsed = now.elapsed();
-    println!("Elapsed: {:.2?}", elapsed);
+    let options = match Options::from_env() {
+        Ok(options) => options,
+        Err(e) => {
+            println!("{e}");
+            process::exit(2);
+        }
+    };
+
+    runner::install_panic_hook();
+
+    match options.command {
+        Command::Bench => process::exit(run_bench(&options)),
+        Command::List => {
+            list_days(&options);
+            return;
+        },
+        Command::Lint => process::exit(lint_days(&options)),
+        Command::New => process::exit(new_day(&options)),
+        Command::Watch => watch::watch(&options, &Log::new(options.log_level)),
+        Command::Repl => {
+            if let Err(e) = repl::Repl::new(&options).run(&mut io::stdin().lock(), &mut io::stdout()) {
+                println!("{e}");
+                process::exit(1);
+            }
+            return;
+        },
+        Command::Run => {},
+    }
+
+    let log = Log::new(options.log_level);
+    let mut results = Vec::new();
+    for (registration, input) in load_days(&options, &log) {
+        let params = day_params(&options, registration.day);
+        results.extend(run_day(registration, Arc::from(input), &params, &log, options.timeout));
+    }
+
+    print_diagnostics(&results);
+    if options.check {
+        process::exit(check_answers(&options, &results));
+    } else if options.record {
+        record_answers(&options, &results);
+    } else {
+        match options.format {
+            Format::Json => print_json(&results),
+            Format::Csv => print_csv(&results),
+            Format::Text if options.is_single_day() => print_single_day(&results),
+            Format::Text => print_table(&results),
+        }
+    }
+
+    if results.iter().any(|result| matches!(result.status, Status::Failed | Status::Panicked | Status::TimedOut)) {
+        process::exit(1);
+    }
 } 
 
-fn get_day() -> Option<usize> {
-    let args: Vec<String> = env::args().collect();
+// Resolves the selected days to their registrations and inputs.
+fn load_days(options: &Options, log: &Log) -> Vec<(&'static Registration, String)> {
+    let mut days = Vec::new();
+    for day in options.first_day..=options.last_day {
+        let registration = match years::get(options.year, day) {
+            Some(registration) => registration,
+            None if options.is_single_day() => {
+                println!("No solution for day {day} of {}", options.year);
+                process::exit(1);
+            },
+            None => continue,
+        };
+        // A range skips days without an input file
+        let input_name = options.input_path(day);
+        if !options.is_single_day() && !Path::new(&input_name).exists() {
+            log.info(|| format!("Skipping day {day}: no input at {input_name}"));
+            continue;
+        }
+        if options.is_single_day() && options.format == Format::Text {
+            println!("{input_name}");
+        }
+        let input = read_file_or_exit(&input_name);
+        days.push((registration, input));
+    }
+    days
+}
+
+// A day's parameter overrides: its example's own when running on the
+// example, followed by any given with --param.
+fn day_params(options: &Options, day: usize) -> Vec<(String, String)> {
+    let mut params = Vec::new();
+    if options.example {
+        let example = options.example(day);
+        match example.params() {
+            Ok(example_params) => params = example_params,
+            Err(e) => {
+                println!("Unable to read parameters {}: {e}", example.params_path);
+                process::exit(1);
+            }
+        }
+    }
+    params.extend(options.params.iter().cloned());
+    params
+}
 
-    let arg = match args.get(1) {
-        Some(val) => val,
-        None => {
-            println!("Not enough arguments provided!");
-            return None;
+fn list_days(options: &Options) {
+    println!("Advent of Code {}", options.year);
+    println!("Day | {:<24} | {:<5} | Input", "Title", "Parts");
+    println!("{:-<3}-+-{:-<24}-+-{:-<5}-+-{:-<5}", "", "", "", "");
+    for day in options.first_day..=options.last_day {
+        let input = if Path::new(&options.input_path(day)).exists() { "yes" } else { "no" };
+        match years::get(options.year, day) {
+            Some(registration) => {
+                let parts: Vec<String> = registration.parts.iter().map(|part| part.to_string()).collect();
+                println!("{:>3} | {:<24} | {:<5} | {}", day, registration.title, parts.join(","), input);
+                for param in registration.params {
+                    println!("{:>3} |   --param {}={}: {}", "", param.name, param.default, param.description);
+                }
+            },
+            None => println!("{:>3} | {:<24} | {:<5} | {}", day, "(not solved)", "-", input),
         }
-    };
+    }
+}
 
-    match arg.parse::<usize>() {
-        Ok(val) => Some(val),
+fn new_day(options: &Options) -> i32 {
+    match scaffold::new_day(options, scaffold::SOURCE_DIR) {
+        Ok(written) => {
+            for path in written {
+                println!("Wrote {path}");
+            }
+            0
+        },
         Err(e) => {
-            println!("Unable to cast argument {} to usize: {}", arg, e);
-            return None;
+            println!("{e}");
+            1
+        }
+    }
+}
+
+// Reports anything suspicious in the selected days' raw inputs. Returns the
+// process exit code: 1 if any input has lints.
+fn lint_days(options: &Options) -> i32 {
+    let mut code = 0;
+    for day in options.first_day..=options.last_day {
+        let input_name = options.input_path(day);
+        if !options.is_single_day() && !Path::new(&input_name).exists() {
+            continue;
+        }
+        let lints = input::lint(&read_file_or_exit(&input_name));
+        if lints.is_empty() {
+            println!("Day {day}: ok");
+            continue;
         }
+        code = 1;
+        for lint in lints {
+            println!("Day {day}: {input_name} {lint}");
+        }
+    }
+    code
+}
+
+// Benchmarks every part of the selected days, comparing against and saving
+// baselines as requested. Returns the process exit code.
+fn run_bench(options: &Options) -> i32 {
+    let bench_options = &options.bench;
+    let baseline = match &bench_options.baseline {
+        None => HashMap::new(),
+        Some(baseline_name) => match bench::load_baseline(baseline_name) {
+            Ok(baseline) => baseline,
+            Err(e) => {
+                println!("Unable to read baseline {baseline_name}: {e}");
+                return 1;
+            }
+        },
+    };
+
+    let log = Log::new(options.log_level);
+    let mut results = Vec::new();
+    for (registration, input) in load_days(options, &log) {
+        let day = registration.day;
+        let solver = registration.problem_with(&day_params(options, day), &log);
+        // Parsing isn't part of the measurement
+        let parsed = match runner::run_parse(solver.as_ref(), &input).0 {
+            Ok(parsed) => parsed,
+            Err(failure) => {
+                println!("Day {day}: {failure}");
+                continue;
+            }
+        };
+        for part in registration.parts.iter().copied() {
+            let stats = match bench::bench_part(solver.as_ref(), part, parsed.as_ref(), bench_options.warmup, bench_options.iterations) {
+                Ok(stats) => stats,
+                Err(message) => {
+                    println!("Day {day} part {part}: {message}");
+                    continue;
+                }
+            };
+            let baseline = baseline.get(&(registration.year, day, part)).copied();
+            results.push(BenchResult { year: registration.year, day, part, stats, baseline });
+        }
+    }
+    bench::print_bench(&results, bench_options.threshold);
+
+    if let Some(baseline_name) = &bench_options.save_baseline {
+        if let Err(e) = bench::save_baseline(baseline_name, &results) {
+            println!("Unable to write baseline {baseline_name}: {e}");
+            return 1;
+        }
+        println!("Saved baseline to {baseline_name}");
+    }
+
+    let regressions = results.iter().filter(|result| result.is_regression(bench_options.threshold)).count();
+    if regressions > 0 {
+        println!("{regressions} part(s) regressed by more than {}%", bench_options.threshold);
+        return 1;
+    }
+    0
+}
+
+fn print_single_day(results: &[PartResult]) {
+    for result in results {
+        if let Some(parse_elapsed) = result.parse_elapsed {
+            println!("Parse elapsed: {:.2?}", parse_elapsed);
+        }
+        println!("Part {} answer: {}", result.part, result.display_answer());
+        println!("Elapsed: {:.2?}", result.elapsed);
     }
 }
-    
-
-// The output is wrapped in a Result to allow matching on errors
-// Returns an Iterator to the Reader of the lines of the file.
-fn read_file(filepath: String) -> String {
-    println!("{filepath}");
-    fs::read_to_string(filepath)
-        .expect("Should have been able to read the file")
+
+// Compares each result against the recorded answers, printing a diff for
+// every mismatch. Returns the process exit code.
+fn check_answers(options: &Options, results: &[PartResult]) -> i32 {
+    let mut mismatches = 0;
+    for result in results.iter().filter(|result| result.status != Status::Unimplemented) {
+        // Recorded answers hold just the value, without the description
+        let actual = match &result.answer {
+            Some(answer) => answer.value.to_string(),
+            None => {
+                println!("Day {} part {}: FAILED, {}", result.day, result.part, result.display_answer());
+                mismatches += 1;
+                continue;
+            },
+        };
+        let answers_name = options.answers_path(result.day);
+        if !answers::exists(&answers_name) {
+            println!("Day {} part {}: no recorded answers at {answers_name}", result.day, result.part);
+            continue;
+        }
+        let expected = match answers::load(&answers_name) {
+            Ok(expected) => expected,
+            Err(e) => {
+                println!("Unable to read answers {answers_name}: {e}");
+                return 1;
+            }
+        };
+        match answers::expected(&expected, result.part) {
+            None => println!("Day {} part {}: no recorded answer", result.day, result.part),
+            Some(expected) => match answers::diff(expected, &actual) {
+                None => println!("Day {} part {}: ok", result.day, result.part),
+                Some(diff) => {
+                    println!("Day {} part {}: MISMATCH", result.day, result.part);
+                    println!("{diff}");
+                    mismatches += 1;
+                },
+            },
+        }
+    }
+
+    if mismatches > 0 {
+        println!("{mismatches} answer(s) did not match");
+        return 1;
+    }
+    0
 }
 
+fn record_answers(options: &Options, results: &[PartResult]) {
+    for day in options.first_day..=options.last_day {
+        let day_results: Vec<&PartResult> = results.iter().filter(|result| result.day == day).collect();
+        if day_results.is_empty() {
+            continue;
+        }
+        let answers_name = options.answers_path(day);
+        match answers::save(&answers_name, &day_results) {
+            Ok(()) => println!("Recorded day {day} answers to {answers_name}"),
+            Err(e) => {
+                println!("Unable to write answers {answers_name}: {e}");
+                process::exit(1);
+            }
+        }
+    }
+}
 
-fn day_to_problem(day: usize) -> Option<Box<dyn Problem>> {
-    match day {
-        1 => Some(Box::new(day_1::DayOne{})),
-        2 => Some(Box::new(day_2::DayTwo{})),
-        3 => Some(Box::new(day_3::DayThree{})),
-        4 => Some(Box::new(day_4::DayFour{})),
-        5 => Some(Box::new(day_5::DayFive{})),
-        6 => Some(Box::new(day_6::DaySix{})),
-        7 => Some(Box::new(day_7::DaySeven{})),
-        8 => Some(Box::new(day_8::DayEight{})),
-        9 => Some(Box::new(day_9::DayNine{})),
-        10 => Some(Box::new(day_10::DayTen{})),
-        11 => Some(Box::new(day_11::DayEleven{})),
-        12 => Some(Box::new(day_12::DayTwelve{})),
-        13 => Some(Box::new(day_13::DayThirteen{})),
-        14 => Some(Box::new(day_14::DayFourteen{})),
-        15 => Some(Box::new(day_15::DayFifteen{})),
-        17 => Some(Box::new(day_17::DaySeventeen{})),
-        18 => Some(Box::new(day_18::DayEighteen{})),
-        19 => Some(Box::new(day_19::DayNineteen{})),
-        20 => Some(Box::new(day_20::DayTwenty{})),
-        21 => Some(Box::new(day_21::DayTwentyOne{})),
-        22 => Some(Box::new(day_22::DayTwentyTwo{})),
-        23 => Some(Box::new(day_23::DayTwentyThree{})),
-        24 => Some(Box::new(day_24::DayTwentyFour{})),
-        25 => Some(Box::new(day_25::DayTwentyFive{})),
-        _ => None
-    }
-}
\ No newline at end of file
+fn read_file_or_exit(filepath: &str) -> String {
+    match runner::read_input(filepath) {
+        Ok(input) => input,
+        Err(e) => {
+            println!("Unable to read input {filepath}: {e}");
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use regex::{Captures, Regex};
+use std::fmt;
+use std::str::FromStr;
+
+// Helpers for parsing puzzle inputs that report where parsing failed instead
+// of panicking. They all take the whole input along with the text being
+// parsed, which must be a slice of the input (as returned by lines(), split(),
+// trim() or a regex capture) so that its line and column can be found.
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseError {
+    // Lines and columns count from 1
+    pub line: usize,
+    pub column: usize,
+    // The text that couldn't be parsed, up to the end of its line
+    pub text: String,
+    pub message: String,
+    // The whole line containing the text
+    pub source_line: String,
+}
+
+impl ParseError {
+    pub fn new(input: &str, text: &str, message: &str) -> ParseError {
+        let offset = offset_in(input, text);
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let source_line = input[line_start..].lines().next().unwrap_or("");
+        ParseError {
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            text: String::from(text.lines().next().unwrap_or("")),
+            message: String::from(message),
+            source_line: String::from(source_line),
+        }
+    }
+
+    // Renders the error with the offending line and a marker under the text.
+    pub fn diagnostic(&self) -> String {
+        let number = self.line.to_string();
+        let gutter = " ".repeat(number.len());
+        let marker = format!("{}{}", " ".repeat(self.column - 1), "^".repeat(self.text.chars().count().max(1)));
+        format!("error: {}\n{gutter}--> line {}, column {}\n{gutter} |\n{number} | {}\n{gutter} | {marker}",
+                self.message, self.line, self.column, self.source_line)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} at line {}, column {}: {:?}", self.message, self.line, self.column, self.text)
+    }
+}
+
+// Byte offset of text within input. Falls back to searching for it when text
+// was copied out of the input rather than sliced from it.
+fn offset_in(input: &str, text: &str) -> usize {
+    (text.as_ptr() as usize).checked_sub(input.as_ptr() as usize)
+        .filter(|offset| offset + text.len() <= input.len())
+        .or_else(|| input.find(text))
+        .unwrap_or(0)
+}
+
+pub fn number<T: FromStr>(input: &str, text: &str) -> Result<T, ParseError> {
+    text.parse().map_err(|_| ParseError::new(input, text, "invalid number"))
+}
+
+pub fn split_once<'t>(input: &str, text: &'t str, delimiter: &str) -> Result<(&'t str, &'t str), ParseError> {
+    text.split_once(delimiter)
+        .ok_or_else(|| ParseError::new(input, text, &format!("expected {delimiter:?}")))
+}
+
+// Matches the regex against text, describing what was expected on failure.
+pub fn captures<'t>(input: &str, regex: &Regex, text: &'t str, expected: &str) -> Result<Captures<'t>, ParseError> {
+    regex.captures(text)
+        .ok_or_else(|| ParseError::new(input, text, &format!("expected {expected}")))
+}
+
+// Parses a named group of a regex match.
+pub fn capture<T: FromStr>(input: &str, captures: &Captures, name: &str) -> Result<T, ParseError> {
+    match captures.name(name) {
+        Some(group) => group.as_str().parse()
+            .map_err(|_| ParseError::new(input, group.as_str(), &format!("invalid {name}"))),
+        None => Err(ParseError::new(input, captures.get(0).unwrap().as_str(), &format!("missing {name}"))),
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "move 1 from 2 to 1\nmove x3 from 1 to 3\n";
+
+    #[test]
+    fn test_error_location() {
+        let line = INPUT.lines().nth(1).unwrap();
+        let error = number::<u32>(INPUT, &line[5..7]).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 6));
+        assert_eq!(error.text, "x3");
+        assert_eq!(error.source_line, "move x3 from 1 to 3");
+        assert_eq!(error.to_string(), "invalid number at line 2, column 6: \"x3\"");
+    }
+
+    #[test]
+    fn test_captures() {
+        let regex = Regex::new(r"^move (?P<count>\S+) from (?P<from>[0-9]+)").unwrap();
+        let line = INPUT.lines().nth(1).unwrap();
+        let cap = captures(INPUT, &regex, line, "a move").unwrap();
+        assert_eq!(capture::<usize>(INPUT, &cap, "from"), Ok(1));
+        let error = capture::<usize>(INPUT, &cap, "count").unwrap_err();
+        assert_eq!((error.line, error.column, error.message.as_str()), (2, 6, "invalid count"));
+        assert!(captures(INPUT, &regex, "stop", "a move").is_err());
+    }
+
+    #[test]
+    fn test_diagnostic() {
+        let error = split_once(INPUT, INPUT.lines().next().unwrap(), ",").unwrap_err();
+        assert_eq!(error.diagnostic(), "error: expected \",\"\n --> line 1, column 1\n  |\n1 | move 1 from 2 to 1\n  | ^^^^^^^^^^^^^^^^^^");
+    }
+}
@@ -1,4 +1,227 @@
-pub trait Problem {
-    fn part_one(&self, input: &str) -> String;
-    fn part_two(&self, input: &str) -> String;
-}
\ No newline at end of file
+use crate::log::Log;
+use crate::parse::ParseError;
+use std::any::Any;
+use std::fmt;
+use std::str::FromStr;
+
+// The input is parsed once and the same structure handed to both parts.
+// Problems and their parsed input are shared with worker threads when parts
+// run under a timeout.
+pub trait Problem: Send + Sync {
+    type Input: Send + Sync + 'static;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ProblemError>;
+    fn part_one(&self, input: &Self::Input) -> Result<Answer, ProblemError>;
+    fn part_two(&self, input: &Self::Input) -> Result<Answer, ProblemError>;
+}
+
+// Parsed input with its type erased, as passed between Solver methods
+pub type Parsed = dyn Any + Send + Sync;
+
+// Object-safe form of Problem, so the registry can hold days whose parsed
+// inputs have different types. Implemented for every Problem.
+pub trait Solver: Send + Sync {
+    fn parse_input(&self, input: &str) -> Result<Box<Parsed>, ProblemError>;
+    // Panics if parsed didn't come from this solver's parse_input
+    fn solve(&self, part: usize, parsed: &Parsed) -> Result<Answer, ProblemError>;
+}
+
+impl<P: Problem> Solver for P {
+    fn parse_input(&self, input: &str) -> Result<Box<Parsed>, ProblemError> {
+        Ok(Box::new(self.parse(input)?))
+    }
+
+    fn solve(&self, part: usize, parsed: &Parsed) -> Result<Answer, ProblemError> {
+        let input = parsed.downcast_ref::<P::Input>().expect("Parsed input is from a different problem");
+        match part {
+            1 => self.part_one(input),
+            2 => self.part_two(input),
+            _ => panic!("Unknown part {part}"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ProblemError {
+    // The input doesn't have the expected format
+    Parse(ParseError),
+    // The input parsed, but the puzzle has no answer for it
+    NoSolution(String),
+    // A parameter was overridden with a value of the wrong type
+    InvalidParam { name: String, value: String },
+    // The part hasn't been solved yet
+    Unimplemented,
+}
+
+impl fmt::Display for ProblemError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ProblemError::Parse(error) => write!(f, "{error}"),
+            ProblemError::NoSolution(message) => write!(f, "no solution: {message}"),
+            ProblemError::InvalidParam { name, value } => write!(f, "invalid value {value:?} for parameter {name}"),
+            ProblemError::Unimplemented => write!(f, "not implemented"),
+        }
+    }
+}
+
+impl From<ParseError> for ProblemError {
+    fn from(error: ParseError) -> ProblemError {
+        ProblemError::Parse(error)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    Integer(i64),
+    Text(String),
+    // Multi-line output, such as letters drawn on a display
+    Grid(Vec<String>),
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Value::Integer(value) => write!(f, "{value}"),
+            Value::Text(value) => write!(f, "{value}"),
+            Value::Grid(rows) => write!(f, "{}", rows.join("\n")),
+        }
+    }
+}
+
+// The value a part computed, with a human description of what it is.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Answer {
+    pub description: String,
+    pub value: Value,
+}
+
+impl Answer {
+    pub fn integer<T: TryInto<i64>>(description: &str, value: T) -> Answer {
+        let value = match value.try_into() {
+            Ok(value) => value,
+            Err(_) => panic!("{description} does not fit in an i64"),
+        };
+        Answer { description: String::from(description), value: Value::Integer(value) }
+    }
+
+    pub fn text(description: &str, value: &str) -> Answer {
+        Answer { description: String::from(description), value: Value::Text(String::from(value)) }
+    }
+
+    pub fn grid(description: &str, value: &str) -> Answer {
+        let rows = value.lines().map(String::from).collect();
+        Answer { description: String::from(description), value: Value::Grid(rows) }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.value {
+            Value::Grid(_) => write!(f, "{}:\n{}", self.description, self.value),
+            _ => write!(f, "{}: {}", self.description, self.value),
+        }
+    }
+}
+
+// A named value a solver uses, such as a number of rounds, that can be
+// overridden from the command line.
+pub struct Param {
+    pub name: &'static str,
+    pub default: &'static str,
+    pub description: &'static str,
+}
+
+// The values of a day's parameters: the defaults with any overrides applied
+#[derive(Clone, Debug, Default)]
+pub struct Params {
+    values: Vec<(&'static str, String)>,
+}
+
+impl Params {
+    // Overrides of parameters that aren't declared are ignored
+    pub fn new(declared: &[Param], overrides: &[(String, String)]) -> Params {
+        let values = declared.iter().map(|param| {
+            let value = overrides.iter().rev()
+                .find(|(name, _)| name == param.name)
+                .map_or(param.default, |(_, value)| value.as_str());
+            (param.name, String::from(value))
+        }).collect();
+        Params { values }
+    }
+
+    // Panics if the parameter wasn't declared, as that's a bug in the solver
+    pub fn get<T: FromStr>(&self, name: &str) -> Result<T, ProblemError> {
+        let (_, value) = self.values.iter()
+            .find(|(param, _)| *param == name)
+            .unwrap_or_else(|| panic!("Parameter {name} is not declared"));
+        value.parse().map_err(|_| ProblemError::InvalidParam { name: String::from(name), value: value.clone() })
+    }
+}
+
+// Describes a day's solution. Each day module declares one as REGISTRATION
+// and its year module collects them into that year's registry.
+pub struct Registration {
+    pub year: usize,
+    pub day: usize,
+    pub title: &'static str,
+    // Parts with a working solution; the others are never run
+    pub parts: &'static [usize],
+    pub params: &'static [Param],
+    // Builds the solver from its parameters and the log for its diagnostics
+    pub new: fn(Params, Log) -> Box<dyn Solver>,
+}
+
+impl Registration {
+    pub fn problem(&self) -> Box<dyn Solver> {
+        self.problem_with(&[], &Log::default())
+    }
+
+    // Builds the solver with some of its parameters overridden by name
+    pub fn problem_with(&self, overrides: &[(String, String)], log: &Log) -> Box<dyn Solver> {
+        (self.new)(Params::new(self.params, overrides), log.clone())
+    }
+
+    pub fn default_params(&self) -> Params {
+        Params::new(self.params, &[])
+    }
+
+    pub fn has_param(&self, name: &str) -> bool {
+        self.params.iter().any(|param| param.name == name)
+    }
+
+    pub fn is_implemented(&self, part: usize) -> bool {
+        self.parts.contains(&part)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answer_display() {
+        assert_eq!(Answer::integer("Shortest path", 31u32).to_string(), "Shortest path: 31");
+        assert_eq!(Answer::text("Snafu sum", "2=-1=0").to_string(), "Snafu sum: 2=-1=0");
+        assert_eq!(Answer::grid("Display", "#..\n.#.\n").to_string(), "Display:\n#..\n.#.");
+    }
+
+    #[test]
+    fn test_params() {
+        const DECLARED: &[Param] = &[
+            Param { name: "rounds", default: "20", description: "Rounds to play" },
+            Param { name: "key", default: "7", description: "Decryption key" },
+        ];
+        let overrides = vec![(String::from("key"), String::from("x")), (String::from("other"), String::from("1"))];
+        let params = Params::new(DECLARED, &overrides);
+        assert_eq!(params.get::<u32>("rounds"), Ok(20));
+        assert_eq!(params.get::<String>("key"), Ok(String::from("x")));
+        assert_eq!(params.get::<u32>("key").unwrap_err().to_string(), "invalid value \"x\" for parameter key");
+    }
+
+    #[test]
+    fn test_answer_value() {
+        assert_eq!(Answer::integer("Height", 1514285714288u64).value, Value::Integer(1514285714288));
+        assert_eq!(Answer::grid("Display", "#.\n.#").value, Value::Grid(vec![String::from("#."), String::from(".#")]));
+    }
+}
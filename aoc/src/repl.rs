@@ -0,0 +1,289 @@
+use crate::cli::Options;
+use advent_of_code::{examples, years, Level, Log, Registration, Solver};
+use advent_of_code::problem::Parsed;
+use advent_of_code::runner::{self, Failure};
+use std::io::{self, BufRead, Write};
+use std::sync::Arc;
+use std::time::Duration;
+
+const HELP: &str = "Commands:
+  day N              select a day, loading its input if there is one
+  load PATH          load an input file
+  example [NAME]     load one of the day's examples, with its parameters
+  paste              read an input from the following lines, up to a line with only .
+  run [PART]         run both parts on the input, or just one
+  param NAME=VALUE   override one of the day's parameters
+  params             show the day's parameters
+  reset              clear the parameter overrides
+  log LEVEL          show solver diagnostics: quiet, normal, debug or trace
+  help               show this help
+  quit               leave";
+
+// An interactive session: pick a day, load or paste an input, tweak the
+// day's parameters and rerun its parts without restarting.
+pub struct Repl<'a> {
+    options: &'a Options,
+    registration: Option<&'static Registration>,
+    input: Option<String>,
+    params: Vec<(String, String)>,
+    log: Log,
+}
+
+impl<'a> Repl<'a> {
+    pub fn new(options: &'a Options) -> Repl<'a> {
+        Repl { options, registration: None, input: None, params: Vec::new(), log: Log::new(options.log_level) }
+    }
+
+    pub fn run(&mut self, input: &mut impl BufRead, out: &mut impl Write) -> io::Result<()> {
+        writeln!(out, "Type help for the commands")?;
+        if self.options.is_single_day() {
+            let message = self.select_day(self.options.first_day).unwrap_or_else(|e| e);
+            writeln!(out, "{message}")?;
+        }
+
+        loop {
+            write!(out, "{}> ", self.prompt())?;
+            out.flush()?;
+            let mut line = String::new();
+            if input.read_line(&mut line)? == 0 {
+                writeln!(out)?;
+                return Ok(());
+            }
+            let line = line.trim();
+            if line == "quit" || line == "exit" {
+                return Ok(());
+            }
+            let output = self.execute(line, input).unwrap_or_else(|e| e);
+            if !output.is_empty() {
+                writeln!(out, "{output}")?;
+            }
+        }
+    }
+
+    fn prompt(&self) -> String {
+        match self.registration {
+            Some(registration) => format!("{} day {}", registration.year, registration.day),
+            None => format!("{}", self.options.year),
+        }
+    }
+
+    // Carries out one command, returning what to show for it
+    fn execute(&mut self, line: &str, input: &mut impl BufRead) -> Result<String, String> {
+        let (command, argument) = match line.split_once(' ') {
+            Some((command, argument)) => (command, argument.trim()),
+            None => (line, ""),
+        };
+        match (command, argument) {
+            ("", _) => Ok(String::new()),
+            ("help", _) => Ok(String::from(HELP)),
+            ("day", day) => {
+                let day = day.parse().map_err(|_| format!("Invalid day {day}"))?;
+                self.select_day(day)
+            },
+            ("load", "") => Err(String::from("Usage: load PATH")),
+            ("load", path) => self.load(path),
+            ("example", name) => self.load_example(if name.is_empty() { examples::DEFAULT_NAME } else { name }),
+            ("paste", _) => self.paste(input),
+            ("run", "") => self.run_parts(None),
+            ("run", part) => match part.parse() {
+                Ok(part @ (1 | 2)) => self.run_parts(Some(part)),
+                _ => Err(format!("Invalid part {part}, expected 1 or 2")),
+            },
+            ("param", param) => self.set_param(param),
+            ("params", _) => self.show_params(),
+            ("reset", _) => {
+                self.params.clear();
+                Ok(String::from("Cleared the parameter overrides"))
+            },
+            ("log", level) => {
+                let level = match level {
+                    "quiet" => Level::Quiet,
+                    "normal" => Level::Normal,
+                    "debug" => Level::Debug,
+                    "trace" => Level::Trace,
+                    _ => return Err(format!("Unknown log level {level}, expected quiet, normal, debug or trace")),
+                };
+                self.log = Log::new(level);
+                Ok(String::new())
+            },
+            _ => Err(format!("Unknown command {command}, type help for the commands")),
+        }
+    }
+
+    fn registration(&self) -> Result<&'static Registration, String> {
+        self.registration.ok_or(String::from("No day selected, use day N"))
+    }
+
+    fn select_day(&mut self, day: usize) -> Result<String, String> {
+        let registration = years::get(self.options.year, day)
+            .ok_or(format!("No solution for day {day} of {}", self.options.year))?;
+        self.registration = Some(registration);
+        self.input = None;
+        self.params.clear();
+
+        let mut message = format!("Day {day}: {}", registration.title);
+        let path = self.options.input_path(day);
+        if let Ok(contents) = runner::read_input(&path) {
+            self.input = Some(contents);
+            message += &format!("\nLoaded {path}");
+        }
+        if self.options.example {
+            self.params = self.options.example(day).params().map_err(|e| e.to_string())?;
+        }
+        Ok(message)
+    }
+
+    fn load(&mut self, path: &str) -> Result<String, String> {
+        let contents = runner::read_input(path).map_err(|e| format!("Unable to read input {path}: {e}"))?;
+        self.input = Some(contents);
+        Ok(format!("Loaded {path}"))
+    }
+
+    fn load_example(&mut self, name: &str) -> Result<String, String> {
+        let registration = self.registration()?;
+        let example = examples::Example::new(&self.options.examples_dir, registration.year, registration.day, name);
+        let params = example.params().map_err(|e| format!("Unable to read parameters {}: {e}", example.params_path))?;
+        let message = self.load(&example.input_path)?;
+        self.params = params;
+        Ok(message)
+    }
+
+    fn paste(&mut self, input: &mut impl BufRead) -> Result<String, String> {
+        let mut contents = String::new();
+        loop {
+            let mut line = String::new();
+            if input.read_line(&mut line).map_err(|e| e.to_string())? == 0 || line.trim_end() == "." {
+                break;
+            }
+            contents.push_str(&line);
+        }
+        let message = format!("Read {} line(s)", contents.lines().count());
+        self.input = Some(contents);
+        Ok(message)
+    }
+
+    fn set_param(&mut self, param: &str) -> Result<String, String> {
+        let registration = self.registration()?;
+        let (name, value) = param.split_once('=').ok_or(String::from("Usage: param NAME=VALUE"))?;
+        let (name, value) = (name.trim(), value.trim());
+        if !registration.has_param(name) {
+            return Err(format!("Day {} has no parameter {name}", registration.day));
+        }
+        self.params.retain(|(overridden, _)| overridden != name);
+        self.params.push((String::from(name), String::from(value)));
+        Ok(String::new())
+    }
+
+    fn show_params(&self) -> Result<String, String> {
+        let registration = self.registration()?;
+        if registration.params.is_empty() {
+            return Ok(format!("Day {} has no parameters", registration.day));
+        }
+        let lines: Vec<String> = registration.params.iter().map(|param| {
+            match self.params.iter().find(|(name, _)| name == param.name) {
+                Some((_, value)) => format!("{}={} (default {}): {}", param.name, value, param.default, param.description),
+                None => format!("{}={}: {}", param.name, param.default, param.description),
+            }
+        }).collect();
+        Ok(lines.join("\n"))
+    }
+
+    fn run_parts(&self, part: Option<usize>) -> Result<String, String> {
+        let registration = self.registration()?;
+        let input = self.input.as_ref().ok_or(String::from("No input loaded, use load, example or paste"))?;
+        let parts = match part {
+            Some(part) if !registration.is_implemented(part) => return Err(format!("Part {part} isn't implemented")),
+            Some(part) => vec![part],
+            None => registration.parts.to_vec(),
+        };
+
+        let solver: Arc<dyn Solver> = Arc::from(registration.problem_with(&self.params, &self.log));
+        let mut lines = Vec::new();
+        let parsed: Arc<Parsed> = match self.run_parse(solver.clone(), input) {
+            Ok((parsed, elapsed)) => {
+                lines.push(format!("Parse elapsed: {:.2?}", elapsed));
+                Arc::from(parsed)
+            },
+            Err(error) => return Err(format!("Parse failed: {error}")),
+        };
+        for part in parts {
+            match self.run_part(solver.clone(), part, parsed.clone()) {
+                Ok((answer, elapsed)) => {
+                    lines.push(format!("Part {part} answer: {answer}"));
+                    lines.push(format!("Elapsed: {:.2?}", elapsed));
+                },
+                Err(error) => lines.push(format!("Part {part} failed: {error}")),
+            }
+        }
+        Ok(lines.join("\n"))
+    }
+
+    fn run_parse(&self, solver: Arc<dyn Solver>, input: &str) -> Result<(Box<Parsed>, Duration), String> {
+        match runner::run_parse_within(solver, Arc::from(input), self.options.timeout) {
+            Some((Ok(parsed), elapsed)) => Ok((parsed, elapsed)),
+            Some((Err(failure), _)) => Err(describe_failure(&failure)),
+            None => Err(runner::describe_timeout(self.options.timeout.unwrap())),
+        }
+    }
+
+    fn run_part(&self, solver: Arc<dyn Solver>, part: usize, parsed: Arc<Parsed>) -> Result<(String, Duration), String> {
+        match runner::run_part_within(solver, part, parsed, self.options.timeout) {
+            Some((Ok(answer), elapsed)) => Ok((answer.to_string(), elapsed)),
+            Some((Err(failure), _)) => Err(describe_failure(&failure)),
+            None => Err(runner::describe_timeout(self.options.timeout.unwrap())),
+        }
+    }
+}
+
+fn describe_failure(failure: &Failure) -> String {
+    match failure.diagnostic() {
+        Some(diagnostic) => format!("{failure}\n{diagnostic}"),
+        None => failure.to_string(),
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    fn session(args: &[&str], commands: &str) -> String {
+        let mut args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+        args.push(String::from("--examples-dir"));
+        args.push(String::from(concat!(env!("CARGO_MANIFEST_DIR"), "/examples")));
+        let options = Options::parse(&args, String::from("missing")).unwrap();
+        let mut out = Vec::new();
+        Repl::new(&options).run(&mut Cursor::new(commands), &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_paste_and_run() {
+        let output = session(&["repl"], "run\nday 1\nrun\npaste\n1000\n\n2000\n3000\n.\nrun 2\nquit\n");
+        assert!(output.contains("No day selected, use day N"));
+        assert!(output.contains("Day 1: Calorie Counting"));
+        assert!(output.contains("No input loaded"));
+        assert!(output.contains("Read 4 line(s)"));
+        assert!(output.contains("Part 2 answer: Sum of top 3 elf scores: 6000"));
+        assert!(!output.contains("Part 1 answer"));
+    }
+
+    #[test]
+    fn test_example_and_params() {
+        let output = session(&["repl", "15"], "example\nparams\nrun 1\nparam line_to_search=11\nrun 1\nparam rounds=1\nreset\nparams\n");
+        assert!(output.contains("Loaded "));
+        assert!(output.contains("line_to_search=10 (default 2000000)"));
+        assert!(output.contains("Part 1 answer: Not possible beacon locations: 26"));
+        assert!(output.contains("Part 1 answer: Not possible beacon locations: 28"));
+        assert!(output.contains("Day 15 has no parameter rounds"));
+        assert!(output.contains("line_to_search=2000000: Row"));
+    }
+
+    #[test]
+    fn test_parse_failure() {
+        let output = session(&["repl", "1"], "paste\n1000\nabc\n.\nrun\nfly\n");
+        assert!(output.contains("Parse failed: invalid number at line 2, column 1"));
+        assert!(output.contains("Unknown command fly"));
+    }
+}
@@ -0,0 +1,662 @@
+use crate::input;
+use crate::log::Log;
+use crate::problem::{Answer, Parsed, ProblemError, Registration, Solver, Value};
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Arc, mpsc};
+use std::thread;
+use std::time::{Duration, Instant};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Status {
+    Ok,
+    Unimplemented,
+    // The solver returned an error, e.g. for malformed input
+    Failed,
+    Panicked,
+    TimedOut,
+}
+
+impl Status {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Status::Ok => "ok",
+            Status::Unimplemented => "unimplemented",
+            Status::Failed => "failed",
+            Status::Panicked => "panicked",
+            Status::TimedOut => "timeout",
+        }
+    }
+}
+
+pub struct PartResult {
+    pub year: usize,
+    pub day: usize,
+    pub part: usize,
+    pub answer: Option<Answer>,
+    // Why there is no answer, for parts that failed, panicked or timed out
+    pub error: Option<String>,
+    // Where parsing failed, shown under the results for parse errors
+    pub diagnostic: Option<String>,
+    // Time spent parsing the day's input. Both parts share one parse, so only
+    // the day's first result carries it.
+    pub parse_elapsed: Option<Duration>,
+    pub elapsed: Duration,
+    pub status: Status,
+}
+
+impl PartResult {
+    // The answer for human-readable output, marking parts that weren't run
+    pub fn display_answer(&self) -> String {
+        match (&self.answer, &self.error) {
+            (Some(answer), _) => answer.to_string(),
+            (None, Some(error)) => error.clone(),
+            (None, None) => String::from("(not implemented)"),
+        }
+    }
+}
+
+// Why a part produced no answer
+#[derive(Debug)]
+pub enum Failure {
+    Error(ProblemError),
+    // The panic message and location
+    Panic(String),
+}
+
+impl Failure {
+    pub fn status(&self) -> Status {
+        match self {
+            Failure::Error(ProblemError::Unimplemented) => Status::Unimplemented,
+            Failure::Error(_) => Status::Failed,
+            Failure::Panic(_) => Status::Panicked,
+        }
+    }
+
+    pub fn diagnostic(&self) -> Option<String> {
+        match self {
+            Failure::Error(ProblemError::Parse(error)) => Some(error.diagnostic()),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Failure::Error(error) => write!(f, "{error}"),
+            Failure::Panic(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+// Reads the whole input file, or stdin when the path is "-".
+pub fn read_input(filepath: &str) -> io::Result<String> {
+    if filepath == "-" {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input)?;
+        return Ok(input);
+    }
+    fs::read_to_string(filepath)
+}
+
+thread_local! {
+    // Set while run_part is executing a solver on this thread
+    static CATCHING_PANICS: Cell<bool> = const { Cell::new(false) };
+    // Location of the last panic caught on this thread
+    static PANIC_LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+// Wraps the default panic hook so that panics inside run_part are recorded
+// for the result instead of being printed. Panics anywhere else still go
+// through the default hook.
+pub fn install_panic_hook() {
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        if CATCHING_PANICS.with(|catching| catching.get()) {
+            let location = info.location().map(|location| location.to_string());
+            PANIC_LOCATION.with(|last| *last.borrow_mut() = location);
+        } else {
+            default_hook(info);
+        }
+    }));
+}
+
+// Runs f, catching any panic and returning its message and location as the
+// failure.
+fn run_catching<T>(f: impl FnOnce() -> Result<T, ProblemError>) -> (Result<T, Failure>, Duration) {
+    let now = Instant::now();
+    CATCHING_PANICS.with(|catching| catching.set(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING_PANICS.with(|catching| catching.set(false));
+    let elapsed = now.elapsed();
+
+    let result = match result {
+        Ok(result) => result.map_err(Failure::Error),
+        Err(payload) => {
+            let message = panic_message(payload.as_ref());
+            Err(Failure::Panic(match PANIC_LOCATION.with(|last| last.borrow_mut().take()) {
+                Some(location) => format!("panicked at {location}: {message}"),
+                None => format!("panicked: {message}"),
+            }))
+        },
+    };
+    (result, elapsed)
+}
+
+// The input is normalised before parsing, outside the timed section
+pub fn run_parse(solver: &dyn Solver, input: &str) -> (Result<Box<Parsed>, Failure>, Duration) {
+    let input = input::normalise(input);
+    run_catching(|| solver.parse_input(&input))
+}
+
+pub fn run_part(solver: &dyn Solver, part: usize, parsed: &Parsed) -> (Result<Answer, Failure>, Duration) {
+    run_catching(|| solver.solve(part, parsed))
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        String::from(*message)
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        String::from("<non-string panic payload>")
+    }
+}
+
+// Runs f on a worker thread, giving up once the timeout has passed. Returns
+// None on timeout. Threads can't be killed, so a timed out part keeps running
+// in the background until the process exits.
+fn run_with_timeout<T: Send + 'static>(timeout: Duration, f: impl FnOnce() -> T + Send + 'static) -> Option<T> {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        // The receiver is gone if we already timed out
+        let _ = sender.send(f());
+    });
+    receiver.recv_timeout(timeout).ok()
+}
+
+pub fn run_parse_with_timeout(solver: Arc<dyn Solver>, input: Arc<str>, timeout: Duration)
+    -> Option<(Result<Box<Parsed>, Failure>, Duration)> {
+    run_with_timeout(timeout, move || run_parse(solver.as_ref(), &input))
+}
+
+pub fn run_part_with_timeout(solver: Arc<dyn Solver>, part: usize, parsed: Arc<Parsed>, timeout: Duration)
+    -> Option<(Result<Answer, Failure>, Duration)> {
+    run_with_timeout(timeout, move || run_part(solver.as_ref(), part, parsed.as_ref()))
+}
+
+// Parses under the timeout if there is one, or on this thread if not. Returns
+// None on timeout.
+pub fn run_parse_within(solver: Arc<dyn Solver>, input: Arc<str>, timeout: Option<Duration>)
+    -> Option<(Result<Box<Parsed>, Failure>, Duration)> {
+    match timeout {
+        Some(timeout) => run_parse_with_timeout(solver, input, timeout),
+        None => Some(run_parse(solver.as_ref(), &input)),
+    }
+}
+
+// Runs the part under the timeout if there is one, or on this thread if not.
+// Returns None on timeout.
+pub fn run_part_within(solver: Arc<dyn Solver>, part: usize, parsed: Arc<Parsed>, timeout: Option<Duration>)
+    -> Option<(Result<Answer, Failure>, Duration)> {
+    match timeout {
+        Some(timeout) => run_part_with_timeout(solver, part, parsed, timeout),
+        None => Some(run_part(solver.as_ref(), part, parsed.as_ref())),
+    }
+}
+
+pub fn describe_timeout(timeout: Duration) -> String {
+    format!("timed out after {:.2?}", timeout)
+}
+
+// Parses the input once, then runs each implemented part on the parsed input.
+// When parsing fails every implemented part fails with the same error. The
+// overrides replace the defaults of the day's parameters by name, and the
+// solver writes its diagnostics to log.
+pub fn run_day(registration: &Registration, input: Arc<str>, overrides: &[(String, String)], log: &Log, timeout: Option<Duration>)
+    -> Vec<PartResult> {
+    let (year, day) = (registration.year, registration.day);
+    let solver: Arc<dyn Solver> = Arc::from(registration.problem_with(overrides, log));
+    let parts: Vec<usize> = [1, 2].into_iter().filter(|part| registration.is_implemented(*part)).collect();
+    let mut results = Vec::new();
+
+    let (mut parsed, parse_elapsed) = match run_parse_within(solver.clone(), input, timeout) {
+        Some((Ok(parsed), elapsed)) => (Ok(Arc::from(parsed)), elapsed),
+        Some((Err(failure), elapsed)) => (Err((failure.to_string(), failure.status(), failure.diagnostic())), elapsed),
+        None => {
+            let timeout = timeout.unwrap();
+            (Err((format!("parsing {}", describe_timeout(timeout)), Status::TimedOut, None)), timeout)
+        },
+    };
+
+    for part in [1, 2] {
+        if !parts.contains(&part) {
+            results.push(PartResult {
+                year, day, part, answer: None, error: None, diagnostic: None, parse_elapsed: None,
+                elapsed: Duration::ZERO, status: Status::Unimplemented
+            });
+            continue;
+        }
+
+        let parsed: &Arc<Parsed> = match &mut parsed {
+            Ok(parsed) => parsed,
+            // Only the first part shows where parsing failed
+            Err((error, status, diagnostic)) => {
+                results.push(PartResult {
+                    year, day, part, answer: None, error: Some(error.clone()), diagnostic: diagnostic.take(),
+                    parse_elapsed: None, elapsed: Duration::ZERO, status: *status
+                });
+                continue;
+            },
+        };
+        let result = match run_part_within(solver.clone(), part, parsed.clone(), timeout) {
+            Some((answer, elapsed)) => {
+                match answer {
+                    Ok(answer) => PartResult {
+                        year, day, part, answer: Some(answer), error: None, diagnostic: None, parse_elapsed: None,
+                        elapsed, status: Status::Ok
+                    },
+                    // A part that reports it isn't solved is shown like one
+                    // that isn't registered
+                    Err(Failure::Error(ProblemError::Unimplemented)) => PartResult {
+                        year, day, part, answer: None, error: None, diagnostic: None, parse_elapsed: None,
+                        elapsed, status: Status::Unimplemented
+                    },
+                    Err(failure) => PartResult {
+                        year, day, part, answer: None, error: Some(failure.to_string()), diagnostic: failure.diagnostic(),
+                        parse_elapsed: None, elapsed, status: failure.status()
+                    },
+                }
+            },
+            None => {
+                let timeout = timeout.unwrap();
+                PartResult {
+                    year, day, part, answer: None, error: Some(describe_timeout(timeout)), diagnostic: None, parse_elapsed: None,
+                    elapsed: timeout, status: Status::TimedOut
+                }
+            },
+        };
+        results.push(result);
+    }
+
+    if let Some(first) = results.iter_mut().find(|result| result.status != Status::Unimplemented) {
+        first.parse_elapsed = Some(parse_elapsed);
+    }
+    results
+}
+
+pub fn print_table(results: &[PartResult]) {
+    // Multi-line answers (e.g. day 10's display) continue on the lines below
+    // their row, aligned with the answer column.
+    let answers: Vec<String> = results.iter().map(|result| result.display_answer()).collect();
+    let answer_width = answers.iter()
+        .flat_map(|answer| answer.lines())
+        .map(|line| line.len())
+        .chain([6])
+        .max()
+        .unwrap();
+
+    println!("{:>3} | {:>4} | {:<answer_width$} | {:>10} | {:>10}", "Day", "Part", "Answer", "Parse", "Elapsed");
+    println!("{:-<3}-+-{:-<4}-+-{:-<answer_width$}-+-{:-<10}-+-{:-<10}", "", "", "", "", "");
+    for (result, answer) in results.iter().zip(&answers) {
+        let mut lines = answer.lines();
+        let first = lines.next().unwrap_or("");
+        let parse_elapsed = result.parse_elapsed.map(|elapsed| format!("{:.2?}", elapsed)).unwrap_or_default();
+        let elapsed = format!("{:.2?}", result.elapsed);
+        println!("{:>3} | {:>4} | {:<answer_width$} | {:>10} | {:>10}", result.day, result.part, first, parse_elapsed, elapsed);
+        for line in lines {
+            println!("{:>3} | {:>4} | {:<answer_width$} | {:>10} | {:>10}", "", "", line, "", "");
+        }
+    }
+    println!("{:-<3}-+-{:-<4}-+-{:-<answer_width$}-+-{:-<10}-+-{:-<10}", "", "", "", "", "");
+
+    let parse_total: Duration = results.iter().filter_map(|result| result.parse_elapsed).sum();
+    let total: Duration = results.iter().map(|result| result.elapsed).sum();
+    let (parse_total, total) = (format!("{:.2?}", parse_total), format!("{:.2?}", total));
+    println!("{:>3} | {:>4} | {:<answer_width$} | {:>10} | {:>10}", "", "", "Total", parse_total, total);
+}
+
+// Prints where parsing failed for each part that hit a parse error. These go
+// to stderr so they don't interfere with json or csv output.
+pub fn print_diagnostics(results: &[PartResult]) {
+    for result in results {
+        if let Some(diagnostic) = &result.diagnostic {
+            eprintln!("Day {} part {}: {diagnostic}", result.day, result.part);
+        }
+    }
+}
+
+pub fn print_json(results: &[PartResult]) {
+    let entries: Vec<String> = results.iter().map(|result| {
+        let (description, answer) = match &result.answer {
+            Some(answer) => (json_string(&answer.description), json_value(&answer.value)),
+            None => (String::from("null"), String::from("null")),
+        };
+        let error = match &result.error {
+            Some(error) => json_string(error),
+            None => String::from("null"),
+        };
+        let parse_ns = match result.parse_elapsed {
+            Some(elapsed) => elapsed.as_nanos().to_string(),
+            None => String::from("null"),
+        };
+        format!(
+            "    {{\"year\": {}, \"day\": {}, \"part\": {}, \"description\": {}, \"answer\": {}, \"parse_ns\": {}, \"duration_ns\": {}, \"status\": \"{}\", \"error\": {}}}",
+            result.year, result.day, result.part, description, answer, parse_ns, result.elapsed.as_nanos(), result.status.name(), error
+        )
+    }).collect();
+    let total: Duration = results.iter().map(|result| result.elapsed + result.parse_elapsed.unwrap_or_default()).sum();
+
+    println!("{{");
+    println!("  \"results\": [");
+    if !entries.is_empty() {
+        println!("{}", entries.join(",\n"));
+    }
+    println!("  ],");
+    println!("  \"total_ns\": {}", total.as_nanos());
+    println!("}}");
+}
+
+pub fn print_csv(results: &[PartResult]) {
+    println!("year,day,part,description,answer,parse_ns,duration_ns,status,error");
+    for result in results {
+        let (description, answer) = match &result.answer {
+            Some(answer) => (answer.description.clone(), answer.value.to_string()),
+            None => (String::new(), String::new()),
+        };
+        let error = result.error.clone().unwrap_or_default();
+        let parse_ns = result.parse_elapsed.map(|elapsed| elapsed.as_nanos().to_string()).unwrap_or_default();
+        println!("{},{},{},{},{},{},{},{},{}", result.year, result.day, result.part, csv_escape(&description),
+                 csv_escape(&answer), parse_ns, result.elapsed.as_nanos(), result.status.name(), csv_escape(&error));
+    }
+}
+
+// Integers are emitted as numbers and grids as an array of rows.
+fn json_value(value: &Value) -> String {
+    match value {
+        Value::Integer(value) => value.to_string(),
+        Value::Text(value) => json_string(value),
+        Value::Grid(rows) => {
+            let rows: Vec<String> = rows.iter().map(|row| json_string(row)).collect();
+            format!("[{}]", rows.join(", "))
+        },
+    }
+}
+
+fn json_string(value: &str) -> String {
+    format!("\"{}\"", json_escape(value))
+}
+
+fn json_escape(value: &str) -> String {
+    let mut escaped = String::new();
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn csv_escape(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        String::from(value)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::log::Level;
+    use crate::parse;
+
+    use crate::problem::{Param, Params, Problem};
+
+    struct Panicking;
+
+    impl Problem for Panicking {
+        type Input = String;
+
+        fn parse(&self, input: &str) -> Result<String, ProblemError> {
+            Ok(String::from(input))
+        }
+
+        fn part_one(&self, input: &String) -> Result<Answer, ProblemError> {
+            Ok(Answer::integer("Length", input.len()))
+        }
+
+        fn part_two(&self, _input: &String) -> Result<Answer, ProblemError> {
+            panic!("Unexpected input");
+        }
+    }
+
+    struct Slow;
+
+    impl Problem for Slow {
+        type Input = ();
+
+        fn parse(&self, _input: &str) -> Result<(), ProblemError> {
+            Ok(())
+        }
+
+        fn part_one(&self, _input: &()) -> Result<Answer, ProblemError> {
+            Ok(Answer::text("Speed", "Fast"))
+        }
+
+        fn part_two(&self, _input: &()) -> Result<Answer, ProblemError> {
+            thread::sleep(Duration::from_secs(5));
+            Ok(Answer::text("Speed", "Slow"))
+        }
+    }
+
+    struct Failing;
+
+    impl Problem for Failing {
+        type Input = u32;
+
+        fn parse(&self, input: &str) -> Result<u32, ProblemError> {
+            Ok(parse::number(input, input.trim())?)
+        }
+
+        fn part_one(&self, input: &u32) -> Result<Answer, ProblemError> {
+            Ok(Answer::integer("Number", *input))
+        }
+
+        fn part_two(&self, _input: &u32) -> Result<Answer, ProblemError> {
+            Err(ProblemError::NoSolution(String::from("nothing to find")))
+        }
+    }
+
+    struct Unsolved;
+
+    impl Problem for Unsolved {
+        type Input = String;
+
+        fn parse(&self, input: &str) -> Result<String, ProblemError> {
+            Ok(String::from(input))
+        }
+
+        fn part_one(&self, input: &String) -> Result<Answer, ProblemError> {
+            Ok(Answer::integer("Length", input.len()))
+        }
+
+        fn part_two(&self, _input: &String) -> Result<Answer, ProblemError> {
+            Err(ProblemError::Unimplemented)
+        }
+    }
+
+    struct Scaled {
+        params: Params,
+    }
+
+    impl Problem for Scaled {
+        type Input = u32;
+
+        fn parse(&self, input: &str) -> Result<u32, ProblemError> {
+            Ok(parse::number(input, input.trim())?)
+        }
+
+        fn part_one(&self, input: &u32) -> Result<Answer, ProblemError> {
+            Ok(Answer::integer("Scaled", input * self.params.get::<u32>("factor")?))
+        }
+
+        fn part_two(&self, _input: &u32) -> Result<Answer, ProblemError> {
+            Ok(Answer::text("Factor", &self.params.get::<String>("factor")?))
+        }
+    }
+
+    struct Logging {
+        log: Log,
+    }
+
+    impl Problem for Logging {
+        type Input = String;
+
+        fn parse(&self, input: &str) -> Result<String, ProblemError> {
+            self.log.trace(|| format!("parsing {input:?}"));
+            Ok(String::from(input))
+        }
+
+        fn part_one(&self, input: &String) -> Result<Answer, ProblemError> {
+            self.log.debug(|| format!("measuring {input:?}"));
+            Ok(Answer::integer("Length", input.len()))
+        }
+
+        fn part_two(&self, _input: &String) -> Result<Answer, ProblemError> {
+            unreachable!()
+        }
+    }
+
+    fn registration(parts: &'static [usize], new: fn(Params, Log) -> Box<dyn Solver>) -> Registration {
+        Registration { year: 2022, day: 1, title: "Test", parts, params: &[], new }
+    }
+
+    #[test]
+    fn test_run_day_isolates_panics() {
+        let results = run_day(&registration(&[1, 2], |_, _| Box::new(Panicking{})), Arc::from("abc"), &[], &Log::default(), None);
+        assert_eq!(results[0].status, Status::Ok);
+        assert_eq!(results[0].answer, Some(Answer::integer("Length", 3)));
+        assert_eq!(results[1].status, Status::Panicked);
+        assert!(results[1].error.as_ref().unwrap().ends_with("Unexpected input"));
+    }
+
+    #[test]
+    fn test_run_day_timeout() {
+        let results = run_day(&registration(&[1, 2], |_, _| Box::new(Slow{})), Arc::from(""), &[], &Log::default(), Some(Duration::from_millis(100)));
+        assert_eq!(results[0].status, Status::Ok);
+        assert_eq!(results[0].answer, Some(Answer::text("Speed", "Fast")));
+        assert_eq!(results[1].status, Status::TimedOut);
+    }
+
+    #[test]
+    fn test_run_day_reports_errors() {
+        let results = run_day(&registration(&[1, 2], |_, _| Box::new(Failing{})), Arc::from("12\n"), &[], &Log::default(), None);
+        assert_eq!(results[0].status, Status::Ok);
+        assert_eq!(results[1].status, Status::Failed);
+        assert_eq!(results[1].error, Some(String::from("no solution: nothing to find")));
+        assert_eq!(results[1].diagnostic, None);
+    }
+
+    #[test]
+    fn test_run_day_parse_error_fails_both_parts() {
+        let results = run_day(&registration(&[1, 2], |_, _| Box::new(Failing{})), Arc::from("12x\n"), &[], &Log::default(), None);
+        for result in &results {
+            assert_eq!(result.status, Status::Failed);
+            assert_eq!(result.error, Some(String::from("invalid number at line 1, column 1: \"12x\"")));
+        }
+        assert!(results[0].diagnostic.as_ref().unwrap().contains("1 | 12x"));
+        assert_eq!(results[1].diagnostic, None);
+    }
+
+    #[test]
+    fn test_run_day_parses_once() {
+        let results = run_day(&registration(&[1, 2], |_, _| Box::new(Failing{})), Arc::from("12\n"), &[], &Log::default(), None);
+        assert_eq!(results[0].answer, Some(Answer::integer("Number", 12)));
+        assert!(results[0].parse_elapsed.is_some());
+        assert_eq!(results[1].parse_elapsed, None);
+    }
+
+    #[test]
+    fn test_run_day_overrides_params() {
+        const PARAMS: &[Param] = &[Param { name: "factor", default: "2", description: "Multiplier" }];
+        let registration = Registration { params: PARAMS, ..registration(&[1, 2], |params, _| Box::new(Scaled{ params })) };
+        let results = run_day(&registration, Arc::from("5"), &[], &Log::default(), None);
+        assert_eq!(results[0].answer, Some(Answer::integer("Scaled", 10)));
+
+        let results = run_day(&registration, Arc::from("5"), &[(String::from("factor"), String::from("3"))], &Log::default(), None);
+        assert_eq!(results[0].answer, Some(Answer::integer("Scaled", 15)));
+
+        let results = run_day(&registration, Arc::from("5"), &[(String::from("factor"), String::from("x"))], &Log::default(), None);
+        assert_eq!(results[0].status, Status::Failed);
+        assert_eq!(results[0].error, Some(String::from("invalid value \"x\" for parameter factor")));
+        assert_eq!(results[1].answer, Some(Answer::text("Factor", "x")));
+    }
+
+    #[test]
+    fn test_run_day_logs_at_level() {
+        let registration = registration(&[1], |_, log| Box::new(Logging{ log }));
+        let log = Log::buffered(Level::Normal);
+        run_day(&registration, Arc::from("abc"), &[], &log, None);
+        assert!(log.messages().is_empty());
+
+        let log = Log::buffered(Level::Trace);
+        run_day(&registration, Arc::from("abc"), &[], &log, Some(Duration::from_secs(5)));
+        assert_eq!(log.messages(), vec!["trace: parsing \"abc\"", "debug: measuring \"abc\""]);
+    }
+
+    #[test]
+    fn test_run_day_normalises_input() {
+        let results = run_day(&registration(&[1], |_, _| Box::new(Panicking{})), Arc::from("abc \r\n\r\n"), &[], &Log::default(), None);
+        assert_eq!(results[0].answer, Some(Answer::integer("Length", 3)));
+    }
+
+    #[test]
+    fn test_run_day_skips_unimplemented() {
+        let results = run_day(&registration(&[1], |_, _| Box::new(Panicking{})), Arc::from("abc"), &[], &Log::default(), None);
+        assert_eq!(results[0].status, Status::Ok);
+        assert_eq!(results[1].status, Status::Unimplemented);
+        assert_eq!(results[1].display_answer(), "(not implemented)");
+    }
+
+    #[test]
+    fn test_run_day_unimplemented_error() {
+        let results = run_day(&registration(&[1, 2], |_, _| Box::new(Unsolved{})), Arc::from("abc"), &[], &Log::default(), None);
+        assert_eq!(results[0].status, Status::Ok);
+        assert_eq!(results[1].status, Status::Unimplemented);
+        assert_eq!(results[1].display_answer(), "(not implemented)");
+    }
+
+    #[test]
+    fn test_json_escape() {
+        assert_eq!(json_escape("Score: 13"), "Score: 13");
+        assert_eq!(json_escape("Display:\n#.\"\\"), "Display:\\n#.\\\"\\\\");
+        assert_eq!(json_escape("\u{1}"), "\\u0001");
+    }
+
+    #[test]
+    fn test_json_value() {
+        assert_eq!(json_value(&Value::Integer(-3)), "-3");
+        assert_eq!(json_value(&Value::Text(String::from("CMZ"))), "\"CMZ\"");
+        assert_eq!(json_value(&Value::Grid(vec![String::from("#."), String::from(".#")])), "[\"#.\", \".#\"]");
+    }
+
+    #[test]
+    fn test_csv_escape() {
+        assert_eq!(csv_escape("Score: 13"), "Score: 13");
+        assert_eq!(csv_escape("a, \"b\""), "\"a, \"\"b\"\"\"");
+        assert_eq!(csv_escape("Display:\n#."), "\"Display:\n#.\"");
+    }
+}
@@ -0,0 +1,312 @@
+use crate::cli::Options;
+use advent_of_code::examples;
+use std::fs;
+use std::path::{Component, Path};
+
+// Where the year modules live, relative to the crate root
+pub const SOURCE_DIR: &str = "./src/years";
+
+const ONES: [&str; 10] = ["", "One", "Two", "Three", "Four", "Five", "Six", "Seven", "Eight", "Nine"];
+const TEENS: [&str; 10] = ["Ten", "Eleven", "Twelve", "Thirteen", "Fourteen", "Fifteen", "Sixteen", "Seventeen", "Eighteen", "Nineteen"];
+
+// Creates a solver skeleton for the selected day, an empty example and input
+// to fill in, and registers the day with its year, creating the year if it's
+// new. The skeleton lists no parts, so it's reported as unimplemented rather
+// than failed until its parts are solved and added. Returns the files created
+// or updated.
+pub fn new_day(options: &Options, source_dir: &str) -> Result<Vec<String>, String> {
+    let (year, day) = (options.year, options.first_day);
+    if !(1..=25).contains(&day) {
+        return Err(format!("Invalid day {day}, expected 1 to 25"));
+    }
+
+    let year_dir = format!("{source_dir}/y{year}");
+    let day_path = format!("{year_dir}/day_{day}.rs");
+    if Path::new(&day_path).exists() {
+        return Err(format!("Day {day} of {year} already exists at {day_path}"));
+    }
+    let title = options.title.clone().unwrap_or(format!("Day {day}"));
+
+    let mut written = Vec::new();
+    let years_path = format!("{source_dir}.rs");
+    let year_path = format!("{source_dir}/y{year}.rs");
+    if Path::new(&year_path).exists() {
+        let registry = read(&year_path)?;
+        write(&year_path, &register_day(&registry, day)?, &mut written)?;
+    } else {
+        let years = read(&years_path)?;
+        write(&years_path, &register_year(&years, year)?, &mut written)?;
+        write(&year_path, &year_source(day), &mut written)?;
+    }
+    let example = examples::Example::new(&options.examples_dir, year, day, examples::DEFAULT_NAME);
+    let example_dir = examples::day_dir(&options.examples_dir, year, day);
+    create_dir(&year_dir)?;
+    create_dir(&example_dir)?;
+    // include_str! paths are relative to the day's source file
+    let example_include = format!("{}/{}.txt", relative_path(&year_dir, &example_dir)?, examples::DEFAULT_NAME);
+    write(&day_path, &day_source(year, day, &title, &example_include), &mut written)?;
+
+    write_new(&example.input_path, "", &mut written)?;
+    write_new(&example.answers_path, "\n---\n", &mut written)?;
+
+    create_dir(&options.year_dir())?;
+    write_new(&options.input_path(day), "", &mut written)?;
+    Ok(written)
+}
+
+fn day_struct_name(day: usize) -> String {
+    let number = match day {
+        1..=9 => String::from(ONES[day]),
+        10..=19 => String::from(TEENS[day - 10]),
+        _ => format!("Twenty{}", ONES[day - 20]),
+    };
+    format!("Day{number}")
+}
+
+// The relative path from one existing directory to another, joined with /
+// as include_str! expects
+fn relative_path(from: &str, to: &str) -> Result<String, String> {
+    let canonical = |path: &str| fs::canonicalize(path).map_err(|e| format!("Unable to resolve {path}: {e}"));
+    let (from, to) = (canonical(from)?, canonical(to)?);
+    let from: Vec<Component> = from.components().collect();
+    let to: Vec<Component> = to.components().collect();
+    let common = from.iter().zip(&to).take_while(|(a, b)| a == b).count();
+
+    let parts: Vec<String> = (common..from.len()).map(|_| String::from(".."))
+        .chain(to[common..].iter().map(|component| component.as_os_str().to_string_lossy().into_owned()))
+        .collect();
+    Ok(if parts.is_empty() { String::from(".") } else { parts.join("/") })
+}
+
+fn day_source(year: usize, day: usize, title: &str, example_include: &str) -> String {
+    let name = day_struct_name(day);
+    format!(r#"use crate::problem::{{Answer, Problem, ProblemError, Registration}};
+
+pub struct {name} {{}}
+
+pub const REGISTRATION: Registration = Registration {{
+    year: {year},
+    day: {day},
+    title: {title:?},
+    parts: &[],
+    params: &[],
+    new: |_, _| Box::new({name}{{}}),
+}};
+
+
+impl Problem for {name} {{
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Vec<String>, ProblemError> {{
+        Ok(input.lines().map(String::from).collect())
+    }}
+
+    fn part_one(&self, _lines: &Vec<String>) -> Result<Answer, ProblemError> {{
+        Err(ProblemError::Unimplemented)
+    }}
+
+    fn part_two(&self, _lines: &Vec<String>) -> Result<Answer, ProblemError> {{
+        Err(ProblemError::Unimplemented)
+    }}
+}}
+
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+
+    const EXAMPLE: &str = include_str!({example_include:?});
+
+    #[test]
+    fn test_parse_example() {{
+        assert!({name}{{}}.parse(EXAMPLE).is_ok());
+    }}
+}}
+"#)
+}
+
+fn year_source(day: usize) -> String {
+    format!("use crate::problem::Registration;\n\nregister_days!(\n    day_{day},\n);\n")
+}
+
+// Adds the day to the year's register_days! list, keeping it in order
+fn register_day(registry: &str, day: usize) -> Result<String, String> {
+    let start = registry.find("register_days!(").ok_or("No register_days! list in the year module")? + "register_days!(".len();
+    let end = start + registry[start..].find(");").ok_or("Unterminated register_days! list")?;
+
+    let mut days = Vec::new();
+    for entry in registry[start..end].split(',').map(|entry| entry.trim()).filter(|entry| !entry.is_empty()) {
+        let registered = entry.strip_prefix("day_").and_then(|number| number.parse::<usize>().ok())
+            .ok_or(format!("Unexpected entry {entry} in register_days!"))?;
+        days.push(registered);
+    }
+    if days.contains(&day) {
+        return Err(format!("Day {day} is already registered"));
+    }
+    days.push(day);
+    days.sort();
+
+    let list: String = days.iter().map(|day| format!("\n    day_{day},")).collect();
+    Ok(format!("{}{list}\n{}", &registry[..start], &registry[end..]))
+}
+
+// Declares the year's module and adds its registry to YEARS after the
+// existing years
+fn register_year(years: &str, year: usize) -> Result<String, String> {
+    let module = format!("pub mod y{year};");
+    if years.contains(&module) {
+        return Err(format!("Year {year} is already declared"));
+    }
+    let mut lines: Vec<String> = years.lines().map(String::from).collect();
+    let last_module = lines.iter().rposition(|line| line.starts_with("pub mod y"))
+        .ok_or("No year modules declared")?;
+    lines.insert(last_module + 1, module);
+    let last_year = lines.iter().rposition(|line| line.trim_start().starts_with('(') && line.contains("::REGISTRY),"))
+        .ok_or("No years listed in YEARS")?;
+    lines.insert(last_year + 1, format!("    ({year}, y{year}::REGISTRY),"));
+    Ok(lines.join("\n") + "\n")
+}
+
+fn read(path: &str) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("Unable to read {path}: {e}"))
+}
+
+fn write(path: &str, contents: &str, written: &mut Vec<String>) -> Result<(), String> {
+    fs::write(path, contents).map_err(|e| format!("Unable to write {path}: {e}"))?;
+    written.push(String::from(path));
+    Ok(())
+}
+
+// Leaves any existing file alone, such as an input already downloaded
+fn write_new(path: &str, contents: &str, written: &mut Vec<String>) -> Result<(), String> {
+    if Path::new(path).exists() {
+        return Ok(());
+    }
+    write(path, contents, written)
+}
+
+fn create_dir(path: &str) -> Result<(), String> {
+    fs::create_dir_all(path).map_err(|e| format!("Unable to create {path}: {e}"))
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use advent_of_code::{Log, Problem, ProblemError, Registration};
+    use advent_of_code::problem::Answer;
+    use advent_of_code::runner::{self, Status};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_day_struct_name() {
+        assert_eq!(day_struct_name(1), "DayOne");
+        assert_eq!(day_struct_name(16), "DaySixteen");
+        assert_eq!(day_struct_name(20), "DayTwenty");
+        assert_eq!(day_struct_name(25), "DayTwentyFive");
+    }
+
+    #[test]
+    fn test_relative_path() {
+        let root = std::env::temp_dir().join(format!("aoc_relative_{}", std::process::id()));
+        let root = root.to_str().unwrap();
+        fs::create_dir_all(format!("{root}/src/years/y2022")).unwrap();
+        fs::create_dir_all(format!("{root}/examples/2022/day_1")).unwrap();
+        assert_eq!(relative_path(&format!("{root}/src/years/y2022"), &format!("{root}/examples/2022/day_1")),
+                   Ok(String::from("../../../examples/2022/day_1")));
+        assert_eq!(relative_path(&format!("{root}/examples"), &format!("{root}/examples/2022/day_1")),
+                   Ok(String::from("2022/day_1")));
+        assert_eq!(relative_path(&format!("{root}/examples"), &format!("{root}/examples")), Ok(String::from(".")));
+        assert!(relative_path(&format!("{root}/missing"), root).is_err());
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_register_day() {
+        let registry = "use crate::problem::Registration;\n\nregister_days!(\n    day_1,\n    day_15,\n    day_17,\n);\n";
+        assert_eq!(register_day(registry, 16), Ok(String::from(
+            "use crate::problem::Registration;\n\nregister_days!(\n    day_1,\n    day_15,\n    day_16,\n    day_17,\n);\n"
+        )));
+        assert!(register_day(registry, 15).is_err());
+        assert_eq!(register_day(&year_source(3), 1), Ok(String::from(
+            "use crate::problem::Registration;\n\nregister_days!(\n    day_1,\n    day_3,\n);\n"
+        )));
+    }
+
+    #[test]
+    fn test_register_year() {
+        let years = "pub mod y2022;\n\npub const YEARS: &[(usize, &[Registration])] = &[\n    (2022, y2022::REGISTRY),\n];\n";
+        assert_eq!(register_year(years, 2023), Ok(String::from(
+            "pub mod y2022;\npub mod y2023;\n\npub const YEARS: &[(usize, &[Registration])] = &[\n    (2022, y2022::REGISTRY),\n    (2023, y2023::REGISTRY),\n];\n"
+        )));
+        assert!(register_year(years, 2022).is_err());
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = std::env::temp_dir().join(format!("aoc_scaffold_{}", std::process::id()));
+        let root = root.to_str().unwrap();
+        let source_dir = format!("{root}/years");
+        fs::create_dir_all(format!("{source_dir}/y2022")).unwrap();
+        fs::write(format!("{source_dir}.rs"), "pub mod y2022;\n\npub const YEARS: &[(usize, &[Registration])] = &[\n    (2022, y2022::REGISTRY),\n];\n").unwrap();
+        fs::write(format!("{source_dir}/y2022.rs"), year_source(15)).unwrap();
+
+        let args: Vec<String> = ["new", "16", "--inputs-dir", &format!("{root}/inputs"), "--examples-dir", &format!("{root}/examples"), "--title", "Proboscidea Volcanium"]
+            .iter().map(|arg| arg.to_string()).collect();
+        let options = Options::parse(&args, String::new()).unwrap();
+        let written = new_day(&options, &source_dir).unwrap();
+        assert_eq!(written.len(), 5);
+        assert!(read(&format!("{source_dir}/y2022.rs")).unwrap().contains("day_15,\n    day_16,"));
+        let source = read(&format!("{source_dir}/y2022/day_16.rs")).unwrap();
+        assert!(source.contains("pub struct DaySixteen {}"));
+        assert!(source.contains("include_str!(\"../../examples/2022/day_16/example.txt\")"));
+        assert!(source.contains("title: \"Proboscidea Volcanium\","));
+        assert_eq!(read(&format!("{root}/examples/2022/day_16/example.answers.txt")).unwrap(), "\n---\n");
+        assert!(Path::new(&format!("{root}/inputs/2022/input_16.txt")).exists());
+        assert!(new_day(&options, &source_dir).is_err());
+
+        let args: Vec<String> = ["new", "1", "--year", "2030", "--inputs-dir", &format!("{root}/inputs"), "--examples-dir", &format!("{root}/examples")]
+            .iter().map(|arg| arg.to_string()).collect();
+        let options = Options::parse(&args, String::new()).unwrap();
+        new_day(&options, &source_dir).unwrap();
+        assert!(read(&format!("{source_dir}.rs")).unwrap().contains("    (2030, y2030::REGISTRY),"));
+        assert_eq!(read(&format!("{source_dir}/y2030.rs")).unwrap(), year_source(1));
+        assert!(read(&format!("{source_dir}/y2030/day_1.rs")).unwrap().contains("pub struct DayOne {}"));
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    // Mirrors the skeleton day_source generates
+    struct Skeleton;
+
+    impl Problem for Skeleton {
+        type Input = Vec<String>;
+
+        fn parse(&self, input: &str) -> Result<Vec<String>, ProblemError> {
+            Ok(input.lines().map(String::from).collect())
+        }
+
+        fn part_one(&self, _lines: &Vec<String>) -> Result<Answer, ProblemError> {
+            Err(ProblemError::Unimplemented)
+        }
+
+        fn part_two(&self, _lines: &Vec<String>) -> Result<Answer, ProblemError> {
+            Err(ProblemError::Unimplemented)
+        }
+    }
+
+    #[test]
+    fn test_scaffolded_day_is_unimplemented() {
+        let source = day_source(2022, 16, "Day 16", "../../../examples/2022/day_16/example.txt");
+        assert!(source.contains("    parts: &[],\n"));
+        assert_eq!(source.matches("Err(ProblemError::Unimplemented)").count(), 2);
+
+        // Run on the empty input placeholder, both as generated and once a
+        // part is listed before it's solved
+        for parts in [&[][..], &[1, 2][..]] {
+            let registration = Registration { year: 2022, day: 16, title: "Day 16", parts, params: &[], new: |_, _| Box::new(Skeleton) };
+            let results = runner::run_day(&registration, Arc::from(""), &[], &Log::default(), None);
+            assert!(results.iter().all(|result| result.status == Status::Unimplemented));
+        }
+    }
+}
@@ -0,0 +1,122 @@
+use crate::cli::Options;
+use advent_of_code::{years, Log, Registration};
+use advent_of_code::runner::{self, Status};
+use std::collections::HashMap;
+use std::fs;
+use std::io::{self, IsTerminal};
+use std::sync::Arc;
+use std::thread;
+use std::time::SystemTime;
+
+// A file polled for changes to its modification time
+pub struct Watched {
+    pub path: String,
+    modified: Option<SystemTime>,
+}
+
+impl Watched {
+    pub fn new(path: String) -> Watched {
+        Watched { path, modified: None }
+    }
+
+    // Whether the file has been modified since the last poll. A missing file
+    // counts as unchanged until it appears.
+    pub fn poll(&mut self) -> bool {
+        let modified = fs::metadata(&self.path).and_then(|metadata| metadata.modified()).ok();
+        if modified.is_none() || modified == self.modified {
+            return false;
+        }
+        self.modified = modified;
+        true
+    }
+}
+
+// Reruns each selected day whenever its input changes, until interrupted.
+// Answers that differ from the day's previous run are highlighted.
+pub fn watch(options: &Options, log: &Log) -> ! {
+    let mut days: Vec<(&Registration, Watched)> = (options.first_day..=options.last_day)
+        .filter_map(|day| years::get(options.year, day))
+        .map(|registration| (registration, Watched::new(options.input_path(registration.day))))
+        .collect();
+    let highlight = io::stdout().is_terminal();
+    let mut previous: HashMap<(usize, usize), String> = HashMap::new();
+
+    log.info(|| format!("Watching {} input(s), press Ctrl-C to stop", days.len()));
+    loop {
+        for (registration, input) in days.iter_mut() {
+            if !input.poll() {
+                continue;
+            }
+            let contents = match runner::read_input(&input.path) {
+                Ok(contents) => contents,
+                Err(e) => {
+                    println!("Unable to read input {}: {e}", input.path);
+                    continue;
+                }
+            };
+            let params = crate::day_params(options, registration.day);
+            let results = runner::run_day(registration, Arc::from(contents), &params, log, options.timeout);
+            match results.iter().find_map(|result| result.parse_elapsed) {
+                Some(parse_elapsed) => println!("Day {}: {} (parsed in {:.2?})", registration.day, input.path, parse_elapsed),
+                None => println!("Day {}: {}", registration.day, input.path),
+            }
+            runner::print_diagnostics(&results);
+            for result in results.iter().filter(|result| result.status != Status::Unimplemented) {
+                let answer = result.display_answer();
+                let change = describe_change(previous.get(&(result.day, result.part)), &answer);
+                let line = format!("Part {} answer: {}{} ({:.2?})", result.part, answer, change.clone().unwrap_or_default(), result.elapsed);
+                match change {
+                    Some(_) if highlight => println!("\x1b[1;33m{line}\x1b[0m"),
+                    _ => println!("{line}"),
+                }
+                previous.insert((result.day, result.part), answer);
+            }
+        }
+        thread::sleep(options.interval);
+    }
+}
+
+// Notes how an answer differs from the previous run's, or None if it's the
+// first run or the answer is the same.
+fn describe_change(previous: Option<&String>, answer: &str) -> Option<String> {
+    let previous = previous?;
+    if previous == answer {
+        None
+    } else if previous.contains('\n') || answer.contains('\n') {
+        Some(String::from("\n(changed)"))
+    } else {
+        Some(format!(" (changed, was {previous})"))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+    use std::time::Duration;
+
+    #[test]
+    fn test_describe_change() {
+        assert_eq!(describe_change(None, "Score: 13"), None);
+        assert_eq!(describe_change(Some(&String::from("Score: 13")), "Score: 13"), None);
+        assert_eq!(describe_change(Some(&String::from("Score: 12")), "Score: 13"), Some(String::from(" (changed, was Score: 12)")));
+        assert_eq!(describe_change(Some(&String::from("Display:\n#.")), "Display:\n.#"), Some(String::from("\n(changed)")));
+    }
+
+    #[test]
+    fn test_poll() {
+        let path = std::env::temp_dir().join(format!("aoc_watch_{}.txt", std::process::id()));
+        let mut watched = Watched::new(String::from(path.to_str().unwrap()));
+        assert!(!watched.poll());
+
+        fs::write(&path, "1").unwrap();
+        assert!(watched.poll());
+        assert!(!watched.poll());
+
+        let file = File::options().write(true).open(&path).unwrap();
+        file.set_modified(SystemTime::now() + Duration::from_secs(10)).unwrap();
+        assert!(watched.poll());
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,63 @@
+use crate::problem::Registration;
+
+// Declares each day module of a year and collects their REGISTRATIONs into
+// the year's REGISTRY, so a new day only needs adding to the year's list.
+macro_rules! register_days {
+    ($($day:ident),* $(,)?) => {
+        $(pub mod $day;)*
+
+        pub const REGISTRY: &[Registration] = &[$($day::REGISTRATION),*];
+    };
+}
+
+pub mod y2022;
+
+pub const YEARS: &[(usize, &[Registration])] = &[
+    (2022, y2022::REGISTRY),
+];
+
+pub fn latest() -> usize {
+    YEARS.iter().map(|(year, _)| *year).max().unwrap()
+}
+
+pub fn registry(year: usize) -> Option<&'static [Registration]> {
+    YEARS.iter().find(|(registered, _)| *registered == year).map(|(_, registry)| *registry)
+}
+
+pub fn get(year: usize, day: usize) -> Option<&'static Registration> {
+    registry(year)?.iter().find(|registration| registration.day == day)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_days_unique_and_ordered() {
+        for (year, registry) in YEARS {
+            assert!(registry.iter().all(|registration| registration.year == *year));
+            let days: Vec<usize> = registry.iter().map(|registration| registration.day).collect();
+            let mut sorted = days.clone();
+            sorted.sort();
+            sorted.dedup();
+            assert_eq!(days, sorted);
+        }
+    }
+
+    #[test]
+    fn test_registry_parts() {
+        for registration in YEARS.iter().flat_map(|(_, registry)| registry.iter()) {
+            // Newly scaffolded days list no parts until they're solved
+            assert!(registration.parts.iter().all(|part| *part == 1 || *part == 2), "Day {} has an unknown part", registration.day);
+            assert!(registration.parts.windows(2).all(|parts| parts[0] < parts[1]), "Day {} lists its parts out of order", registration.day);
+        }
+    }
+
+    #[test]
+    fn test_get() {
+        assert_eq!(get(2022, 12).unwrap().title, "Hill Climbing Algorithm");
+        assert!(get(2022, 16).is_none());
+        assert!(get(1999, 1).is_none());
+    }
+}
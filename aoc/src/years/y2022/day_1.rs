@@ -0,0 +1,89 @@
+use crate::parse::{self, ParseError};
+use crate::problem::{Answer, Problem, ProblemError, Registration};
+
+pub struct DayOne {}
+
+pub const REGISTRATION: Registration = Registration {
+    year: 2022,
+    day: 1,
+    title: "Calorie Counting",
+    parts: &[1, 2],
+    params: &[],
+    new: |_, _| Box::new(DayOne{}),
+};
+
+fn parse(input: &str) -> Result<Vec<i32>, ParseError> {
+    // The current elf's total, once it has any lines
+    let mut sum = None;
+    let mut elf_scores = Vec::new();
+    for line in input.split("\n") {
+        if line.trim() == "" {
+            elf_scores.extend(sum.take());
+        } else {
+            *sum.get_or_insert(0) += parse::number::<i32>(input, line.trim())?;
+        }
+    }
+    // Normalised input has no blank line after the last elf
+    elf_scores.extend(sum);
+
+    Ok(elf_scores)
+}
+
+fn no_elves() -> ProblemError {
+    ProblemError::NoSolution(String::from("no elf totals found"))
+}
+
+
+impl Problem for DayOne {
+    type Input = Vec<i32>;
+
+    fn parse(&self, input: &str) -> Result<Vec<i32>, ProblemError> {
+        Ok(parse(input)?)
+    }
+
+    fn part_one(&self, elf_scores: &Vec<i32>) -> Result<Answer, ProblemError> {
+        let max_value = elf_scores.iter().max();
+        match max_value {
+            Some(max) => Ok(Answer::integer("Maximum elf score", *max)),
+            None      => Err(no_elves()),
+        }
+    }
+
+    fn part_two(&self, elf_scores: &Vec<i32>) -> Result<Answer, ProblemError> {
+        if elf_scores.is_empty() {
+            return Err(no_elves());
+        }
+        let mut elf_scores = elf_scores.clone();
+        // Part 2
+        elf_scores.sort();
+        elf_scores.reverse();
+        elf_scores.truncate(3);
+        let top_3_total: i32 = elf_scores.iter().sum();
+        Ok(Answer::integer("Sum of top 3 elf scores", top_3_total))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_d1_malformed() {
+        let error = parse("1000\n2OOO\n").unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (2, 1, "2OOO"));
+    }
+
+    #[test]
+    fn test_d1_zero_total_last() {
+        assert_eq!(parse("1000\n\n0\n0").unwrap(), vec![1000, 0]);
+        assert_eq!(parse("0\n\n\n5").unwrap(), vec![0, 5]);
+    }
+
+    #[test]
+    fn test_d1_empty() {
+        let elf_scores = parse("").unwrap();
+        assert!(elf_scores.is_empty());
+        assert!(matches!(DayOne{}.part_one(&elf_scores), Err(ProblemError::NoSolution(_))));
+        assert!(matches!(DayOne{}.part_two(&elf_scores), Err(ProblemError::NoSolution(_))));
+    }
+}
\ No newline at end of file
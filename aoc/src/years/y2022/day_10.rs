@@ -0,0 +1,112 @@
+use crate::parse::{self, ParseError};
+use crate::problem::{Answer, Problem, ProblemError, Registration};
+
+pub struct DayTen {}
+
+pub const REGISTRATION: Registration = Registration {
+    year: 2022,
+    day: 10,
+    title: "Cathode-Ray Tube",
+    parts: &[1, 2],
+    params: &[],
+    new: |_, _| Box::new(DayTen{}),
+};
+
+pub enum Instruction {
+    Noop,
+    Addx(i32),
+}
+
+struct CathodeRay {
+    sum: i32,
+    cycle_count: u32,
+    register: i32,
+    render_buf: String
+}
+
+impl CathodeRay {
+    pub fn print_display(&self) -> &String {
+        return &self.render_buf
+    }
+
+    fn render(&mut self) {
+        let pixel_position = self.cycle_count as i32 - 1;
+        if (pixel_position % 40 - self.register).abs() <= 1 {
+            self.render_buf.push_str("#");
+        } else {
+            self.render_buf.push_str(".");
+        }
+        
+        if self.cycle_count % 40 == 0 {
+            self.render_buf.push_str("\n");
+        }
+    }
+
+    fn increment_cycle_count(&mut self, register: Option<i32>) {
+        self.render();
+        self.cycle_count += 1;
+        if let Some(x) = register {
+            self.register += x;
+        }
+        if self.cycle_count % 40 == 20 {
+            self.sum += self.register * self.cycle_count as i32;
+        }
+    }
+
+    pub fn process_signal(&mut self, instructions: &[Instruction]) -> i32 {
+        for instruction in instructions {
+            match instruction {
+                Instruction::Noop => self.increment_cycle_count(None),
+                Instruction::Addx(register) => {
+                    self.increment_cycle_count(None);
+                    self.increment_cycle_count(Some(*register));
+                },
+            }
+        }
+
+        self.sum
+    }
+
+    pub fn new() -> CathodeRay {
+        let render_buf = String::new();
+        CathodeRay { sum:0, cycle_count:1, register:1, render_buf:render_buf }
+    }
+}
+
+
+fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    let mut instructions = Vec::new();
+    for line in input.lines() {
+        if line == "noop" {
+            instructions.push(Instruction::Noop);
+        } else if let Some(register) = line.strip_prefix("addx ") {
+            instructions.push(Instruction::Addx(parse::number(input, register)?));
+        } else {
+            return Err(ParseError::new(input, line, "expected noop or addx"));
+        }
+    }
+
+    Ok(instructions)
+}
+
+
+impl Problem for DayTen {
+    type Input = Vec<Instruction>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Instruction>, ProblemError> {
+        Ok(parse(input)?)
+    }
+
+    fn part_one(&self, instructions: &Vec<Instruction>) -> Result<Answer, ProblemError> {
+        let mut cathode_ray = CathodeRay::new();
+        let register_sum = cathode_ray.process_signal(instructions);
+        Ok(Answer::integer("Sum of registers", register_sum))
+    }
+
+    fn part_two(&self, instructions: &Vec<Instruction>) -> Result<Answer, ProblemError> {
+        let mut cathode_ray = CathodeRay::new();
+        cathode_ray.process_signal(instructions);
+        let display = cathode_ray.print_display();
+        Ok(Answer::grid("Display", display))
+    }
+}
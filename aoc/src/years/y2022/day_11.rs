@@ -0,0 +1,184 @@
+use crate::parse::{self, ParseError};
+use crate::problem::{Answer, Param, Params, Problem, ProblemError, Registration};
+use std::collections::{HashMap, HashSet};
+use regex::Regex;
+
+pub struct DayEleven {
+    params: Params,
+}
+
+pub const REGISTRATION: Registration = Registration {
+    year: 2022,
+    day: 11,
+    title: "Monkey in the Middle",
+    parts: &[1, 2],
+    params: &[
+        Param { name: "part_one_rounds", default: "20", description: "Rounds played in part one" },
+        Param { name: "part_two_rounds", default: "10000", description: "Rounds played in part two" },
+    ],
+    new: |params, _| Box::new(DayEleven { params }),
+};
+
+#[derive(Clone)]
+enum Operation {
+    Multiply,
+    Add
+}
+
+#[derive(Clone)]
+struct Monkey {
+    num: u8, 
+    items_inspected: u64,
+    items: Vec<u64>,
+    operation: Operation,
+    change: Option<u8>,
+    divisible: u8,
+    true_monkey: u8, 
+    false_monkey: u8,
+}
+
+impl Monkey {
+    pub fn play_round(&mut self, worry_level_divide: bool) -> Vec<(u64, u8)> {
+        let mut throws = Vec::new();
+        for item in &self.items {
+            self.items_inspected += 1;
+            let change: u64 = match self.change {
+                Some(x) => x as u64,
+                None => *item,
+            };
+            let mut worry_level = match self.operation {
+                Operation::Multiply => item * change,
+                Operation::Add => item + change,
+            };
+            if worry_level_divide {
+                worry_level = worry_level/3;
+            }
+
+            if worry_level % self.divisible as u64 == 0 {
+                throws.push((worry_level, self.true_monkey));
+            } else {
+                throws.push((worry_level, self.false_monkey));
+            }
+        }
+
+        // Empty items 
+        self.items = Vec::new();
+
+        throws
+    }
+
+    pub fn throw(&mut self, item: u64) {
+        self.items.push(item)
+    }
+
+    pub fn new(input: &str, monkey_input: &str) -> Result<Monkey, ParseError> {
+        lazy_static! {
+            static ref RE: Regex = Regex::new(r"^Monkey\s(?P<monkey_num>[0-9]+):\n\s*Starting\sitems:(?P<items>[0-9,\s]+)\n\s*Operation:\snew\s=\sold\s(?P<operation>[+*])\s(?P<change>(old|[0-9]+))\n\s*Test:\sdivisible\sby\s(?P<divisible>[1-9][0-9]*)\n\s*If\strue:\sthrow\sto\smonkey\s(?P<true_monkey>[0-9]+)\n\s*If\sfalse:\sthrow\sto\smonkey\s(?P<false_monkey>[0-9]+)$").unwrap();
+        };
+        let cap = parse::captures(input, &RE, monkey_input, "a monkey")?;
+        let monkey_num: u8 = parse::capture(input, &cap, "monkey_num")?;
+        let mut items: Vec<u64> = Vec::new();
+        for item in cap.name("items").unwrap().as_str().split(",") {
+            items.push(parse::number(input, item.trim())?);
+        }
+        let operation = match cap.name("operation").unwrap().as_str() {
+            "+" => Operation::Add,
+            _ => Operation::Multiply,
+        };
+        let change_str: &str = cap.name("change").unwrap().as_str();
+        let change = match change_str {
+            "old" => None,
+            _ => Some(parse::number(input, change_str)?)
+        };
+        let divisible: u8 = parse::capture(input, &cap, "divisible")?;
+        let true_monkey: u8 = parse::capture(input, &cap, "true_monkey")?;
+        let false_monkey: u8 = parse::capture(input, &cap, "false_monkey")?;
+
+        Ok(Monkey { 
+            num:monkey_num,
+            items_inspected:0, items:items, 
+            operation:operation, change:change,
+            divisible:divisible, true_monkey:true_monkey, 
+            false_monkey:false_monkey
+        })
+    }
+}
+
+#[derive(Clone)]
+pub struct MonkeyThrower {
+    monkeys: HashMap<u8, Monkey>,
+    lcm: u64
+}
+
+impl MonkeyThrower {
+    pub fn score(&self) -> u64 {
+        let mut monkey_scores = Vec::new();
+        for monkey in self.monkeys.values() {
+            monkey_scores.push(monkey.items_inspected);
+        }
+        monkey_scores.sort();
+        return monkey_scores[self.monkeys.len() - 1] * monkey_scores[self.monkeys.len() - 2]
+    }
+
+    pub fn run(&mut self, round_num: u32, worry_level_divide: bool) {
+        let mut monkey_keys: Vec<u8> = self.monkeys.keys().map(|x| *x).collect();
+        monkey_keys.sort();
+        for _ in 0..round_num {
+            for monkey_key in &monkey_keys {
+                let monkey = self.monkeys.get_mut(&monkey_key).unwrap();
+
+                let throws = monkey.play_round(worry_level_divide);
+                for (item, receiving_monkey_key) in throws {
+                    let receiving_monkey = self.monkeys.get_mut(&receiving_monkey_key).unwrap();
+                    // Manage overflow
+                    let reduced_item = item % self.lcm;
+                    receiving_monkey.throw(reduced_item);
+                }
+            }
+        }
+    }
+
+    pub fn new(input: &str) -> Result<MonkeyThrower, ParseError> {
+        let mut lcm: u64 = 1;
+        let mut parsed = Vec::new();
+        for monkey_input in input.split("\n\n") {
+            let monkey = Monkey::new(input, monkey_input)?;
+            lcm = lcm * monkey.divisible as u64;
+            parsed.push((monkey_input, monkey));
+        }
+
+        // Every throw must land on a monkey that exists
+        let monkey_nums: HashSet<u8> = parsed.iter().map(|(_, monkey)| monkey.num).collect();
+        for (monkey_input, monkey) in &parsed {
+            if !monkey_nums.contains(&monkey.true_monkey) || !monkey_nums.contains(&monkey.false_monkey) {
+                return Err(ParseError::new(input, monkey_input, "monkey throws to a missing monkey"));
+            }
+        }
+        let monkeys = parsed.into_iter().map(|(_, monkey)| (monkey.num, monkey)).collect();
+        
+        Ok(MonkeyThrower { monkeys:monkeys, lcm:lcm })
+    }
+}
+
+
+impl Problem for DayEleven {
+    type Input = MonkeyThrower;
+
+    fn parse(&self, input: &str) -> Result<MonkeyThrower, ProblemError> {
+        Ok(MonkeyThrower::new(input)?)
+    }
+
+    fn part_one(&self, monkey_thrower: &MonkeyThrower) -> Result<Answer, ProblemError> {
+        let mut monkey_thrower = monkey_thrower.clone();
+        monkey_thrower.run(self.params.get("part_one_rounds")?, true);
+        let score = monkey_thrower.score();
+        Ok(Answer::integer("Monkey business", score))
+    }
+
+    fn part_two(&self, monkey_thrower: &MonkeyThrower) -> Result<Answer, ProblemError> {
+        let mut monkey_thrower = monkey_thrower.clone();
+        monkey_thrower.run(self.params.get("part_two_rounds")?, false);
+        let score = monkey_thrower.score();
+        Ok(Answer::integer("Monkey business", score))
+    }
+}
@@ -0,0 +1,251 @@
+use crate::log::Log;
+use crate::parse::ParseError;
+use crate::problem::{Answer, Problem, ProblemError, Registration};
+use crate::graph::{Graph, NodeIndex, Route};
+use std::collections::{HashMap, HashSet};
+
+#[derive(Default)]
+pub struct DayTwelve {
+    log: Log,
+}
+
+pub const REGISTRATION: Registration = Registration {
+    year: 2022,
+    day: 12,
+    title: "Hill Climbing Algorithm",
+    parts: &[1, 2],
+    params: &[],
+    new: |_, log| Box::new(DayTwelve { log }),
+};
+
+#[derive(Clone)]
+pub struct HeightMap {
+    map: Graph,
+    end: NodeIndex,
+    single_start: NodeIndex,
+    possible_starts: HashSet<NodeIndex>,
+    // The (row, column) of each node, by node index
+    positions: Vec<(usize, usize)>,
+}
+
+impl HeightMap {
+    pub fn shortest_path(&self) -> Option<u32> {
+        self.map.bfs(self.single_start, self.end)
+    }
+
+    pub fn shortest_route(&self) -> Option<Route> {
+        self.map.bfs_route(self.single_start, self.end)
+    }
+
+    // Each step moves one square, so the Manhattan distance to E never
+    // overestimates the steps remaining
+    pub fn shortest_route_astar(&self) -> (Option<Route>, usize) {
+        let end = self.positions[self.end];
+        let search = self.map.astar(self.single_start, self.end, |node| {
+            let (row, column) = self.positions[node];
+            (row.abs_diff(end.0) + column.abs_diff(end.1)) as u32
+        });
+        (search.route(self.end), search.expanded())
+    }
+
+    // Draws the route like the puzzle does, marking each step with the
+    // direction taken from it and the end with E
+    pub fn render_route(&self, route: &Route) -> String {
+        let rows = self.positions.iter().map(|(row, _)| row + 1).max().unwrap_or(0);
+        let columns = self.positions.iter().map(|(_, column)| column + 1).max().unwrap_or(0);
+        let mut grid = vec![vec!['.'; columns]; rows];
+        for step in route.nodes.windows(2) {
+            let (from, to) = (self.positions[step[0]], self.positions[step[1]]);
+            grid[from.0][from.1] = match (to.0 as i32 - from.0 as i32, to.1 as i32 - from.1 as i32) {
+                (-1, 0) => '^',
+                (1, 0) => 'v',
+                (0, -1) => '<',
+                _ => '>',
+            };
+        }
+        if let Some(last) = route.nodes.last() {
+            let (row, column) = self.positions[*last];
+            grid[row][column] = 'E';
+        }
+        grid.iter().map(|row| row.iter().collect::<String>()).collect::<Vec<String>>().join("\n")
+    }
+
+    pub fn shortest_path_from_a(&self) -> Option<u32> {
+        self.shortest_route_from_a().map(|route| route.distance)
+    }
+
+    // One search from every a at once finds whichever is nearest E
+    pub fn shortest_route_from_a(&self) -> Option<Route> {
+        let starts: Vec<NodeIndex> = self.possible_starts.iter().copied().collect();
+        self.map.bfs_route_from_any(&starts, self.end)
+    }
+
+    fn get_letter_value(letter: char) -> i32 {
+        let value = match letter {
+            'S' => 'a',
+            'E' => 'z',
+            _ => letter,
+        };
+        assert!('a' as i32 <= value as i32 && value as i32 <= 'z' as i32);
+        value as i32
+    }
+
+    pub fn new(input: &str) -> Result<HeightMap, ParseError> {
+        let mut single_start = None;
+        let mut end = None;
+        let mut grid: HashMap<(i32, i32), (char, usize)> = HashMap::new();
+        let mut graph = Graph::new();
+        let mut possible_starts = HashSet::new();
+        let mut positions = Vec::new();
+
+        for (i, line) in input.lines().enumerate() {
+            let line = line.trim();
+            for (j, (offset, letter)) in line.char_indices().enumerate() {
+                if !(letter.is_ascii_lowercase() || letter == 'S' || letter == 'E') {
+                    return Err(ParseError::new(input, &line[offset..offset + letter.len_utf8()], "expected a height from a to z, S or E"));
+                }
+                let node_index = graph.add_node();
+                positions.push((i, j));
+                if letter == 'S' {
+                    single_start = Some(node_index);
+                    possible_starts.insert(node_index);
+                } else if letter == 'E' {
+                    end = Some(node_index);
+                } else if letter == 'a' {
+                    possible_starts.insert(node_index);
+                }
+                grid.insert((i as i32, j as i32), (letter, node_index));
+            }
+        }
+
+        // Add edges to graph
+        for (coord, (letter, index)) in &grid {
+            for neighbour in [(coord.0-1, coord.1), (coord.0+1, coord.1), (coord.0, coord.1-1), (coord.0, coord.1+1)] {
+                if grid.contains_key(&neighbour) {
+                    let (neighbour_letter, neighbour_index) = grid.get(&neighbour).unwrap();
+                    if HeightMap::get_letter_value(*neighbour_letter) - HeightMap::get_letter_value(*letter) <= 1 {
+                        graph.add_edge(*index, *neighbour_index);
+                    }  
+                }
+            }
+        } 
+
+        let single_start_val = single_start.ok_or_else(|| ParseError::new(input, input, "no start marked with S"))?;
+        let end_val = end.ok_or_else(|| ParseError::new(input, input, "no end marked with E"))?;
+        Ok(HeightMap { map:graph, single_start:single_start_val, end:end_val, possible_starts:possible_starts, positions })
+    }
+}
+
+impl Problem for DayTwelve {
+    type Input = HeightMap;
+
+    fn parse(&self, input: &str) -> Result<HeightMap, ProblemError> {
+        Ok(HeightMap::new(input)?)
+    }
+
+    fn part_one(&self, map: &HeightMap) -> Result<Answer, ProblemError> {
+        let shortest_path = map.shortest_path()
+            .ok_or(ProblemError::NoSolution(String::from("no path from S to E")))?;
+        self.log.debug(|| {
+            let bfs = map.map.bfs_search(map.single_start, map.end).expanded();
+            let (_, astar) = map.shortest_route_astar();
+            format!("BFS expanded {bfs} nodes, A* expanded {astar}")
+        });
+        self.log.trace(|| match map.shortest_route() {
+            Some(route) => format!("Route taken:\n{}", map.render_route(&route)),
+            None => String::from("No route"),
+        });
+        Ok(Answer::integer("Shortest path", shortest_path))
+    }
+
+    fn part_two(&self, map: &HeightMap) -> Result<Answer, ProblemError> {
+        let shortest_path = map.shortest_path_from_a()
+            .ok_or(ProblemError::NoSolution(String::from("no path from any a to E")))?;
+        Ok(Answer::integer("Shortest path", shortest_path))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::log::Level;
+
+    const EXAMPLE: &str = include_str!("../../../examples/2022/day_12/example.txt");
+
+    #[test]
+    fn test_render_route() {
+        let map = HeightMap::new(EXAMPLE).unwrap();
+        let route = map.shortest_route().unwrap();
+        assert_eq!(route.distance, 31);
+        assert_eq!(route.nodes.len(), 32);
+        assert_eq!(route.nodes.first(), Some(&map.single_start));
+
+        let rendered = map.render_route(&route);
+        assert_eq!(rendered.lines().count(), 5);
+        assert_eq!(rendered.chars().filter(|c| "^v<>".contains(*c)).count(), 31);
+        assert_eq!(rendered.lines().nth(2).unwrap().chars().nth(5), Some('E'));
+        assert!(rendered.starts_with('v') || rendered.starts_with('>'));
+    }
+
+    #[test]
+    fn test_invalid_height() {
+        let error = HeightMap::new("Sab\nbEé").err().unwrap();
+        assert_eq!((error.line, error.column, error.text.as_str()), (2, 3, "é"));
+    }
+
+    #[test]
+    fn test_route_from_a() {
+        let map = HeightMap::new(EXAMPLE).unwrap();
+        let route = map.shortest_route_from_a().unwrap();
+        assert_eq!(route.distance, 29);
+        assert!(map.possible_starts.contains(&route.nodes[0]));
+        // Searching back from E reaches the same nearest a
+        let from_end = map.map.reversed().bfs_from(map.end);
+        assert_eq!(map.possible_starts.iter().filter_map(|start| from_end.distance(*start)).min(), Some(29));
+    }
+
+    // Climbs to a plateau at z, with E at its far end and room either side of
+    // the straight route for a blind search to wander into
+    fn plateau() -> String {
+        let row = |start: char, end: char| format!("{start}bcdefghijklmnopqrstuvwxy{}{end}", "z".repeat(20));
+        [row('a', 'z'), row('a', 'z'), row('S', 'E'), row('a', 'z'), row('a', 'z')].join("\n")
+    }
+
+    #[test]
+    fn test_route_astar() {
+        for input in [String::from(EXAMPLE), plateau()] {
+            let map = HeightMap::new(&input).unwrap();
+            let (route, _) = map.shortest_route_astar();
+            let (route, bfs_route) = (route.unwrap(), map.shortest_route().unwrap());
+            assert_eq!(route.distance, bfs_route.distance);
+            assert_eq!(route.nodes.len(), bfs_route.nodes.len());
+        }
+
+        let map = HeightMap::new(&plateau()).unwrap();
+        let (_, expanded) = map.shortest_route_astar();
+        assert!(expanded < map.map.bfs_search(map.single_start, map.end).expanded());
+    }
+
+    #[test]
+    fn test_part_one_logs_expansions() {
+        let day = DayTwelve { log: Log::buffered(Level::Debug) };
+        let map = day.parse(EXAMPLE).unwrap();
+        day.part_one(&map).unwrap();
+        let messages = day.log.messages();
+        assert_eq!(messages.len(), 1);
+        assert!(messages[0].starts_with("debug: BFS expanded "));
+    }
+
+    #[test]
+    fn test_part_one_traces_route() {
+        let day = DayTwelve { log: Log::buffered(Level::Trace) };
+        let map = day.parse(EXAMPLE).unwrap();
+        day.part_one(&map).unwrap();
+        let messages = day.log.messages();
+        assert_eq!(messages.len(), 2);
+        let route = messages[1].strip_prefix("trace: Route taken:\n").unwrap();
+        assert_eq!(route.lines().count(), 5);
+        assert_eq!(route.matches('E').count(), 1);
+    }
+}
@@ -0,0 +1,237 @@
+use crate::log::Log;
+use crate::parse::{self, ParseError};
+use crate::problem::{Answer, Problem, ProblemError, Registration};
+use std::cmp::Ordering;
+use regex::Regex;
+
+pub struct DayThirteen {
+    log: Log,
+}
+
+pub const REGISTRATION: Registration = Registration {
+    year: 2022,
+    day: 13,
+    title: "Distress Signal",
+    parts: &[1, 2],
+    params: &[],
+    new: |_, log| Box::new(DayThirteen { log }),
+};
+
+enum Comparison {
+    Correct, 
+    Incorrect,
+    Undetermined
+}
+
+fn compare_line_recursive(left: &mut String, right: &mut String) -> Comparison {
+    loop {
+        if left == "" && right == "" {
+            return Comparison::Undetermined
+        } else if left == "" {
+            return Comparison::Correct
+        } else if right == "" {
+            return Comparison::Incorrect
+        }
+
+        let comparison;
+
+        if left.starts_with("]") && right.starts_with("]") {
+            lazy_static! {
+                static ref REGEX_EOL: Regex = Regex::new(r"^\](,)?(?P<remaining>[0-9\s\]\[,]*)$").unwrap();
+            }
+            *left = String::from(REGEX_EOL.captures(left).unwrap().name("remaining").unwrap().as_str());
+            *right = String::from(REGEX_EOL.captures(right).unwrap().name("remaining").unwrap().as_str());
+            comparison = Comparison::Undetermined;
+        } else if left.starts_with("]") && ! right.starts_with("]") {
+            comparison = Comparison::Correct;
+        } else if ! left.starts_with("]") && right.starts_with("]") {
+            comparison = Comparison::Incorrect;
+        } else if left.starts_with("[") && right.starts_with("[") {
+            left.drain(0..1);
+            right.drain(0..1);
+            comparison = compare_line_recursive(left, right);
+        } else if left.starts_with("[") && ! right.starts_with("[") {
+            left.drain(0..1);
+            let mut tmp_string = format!("{}]", right.chars().next().unwrap());
+            comparison = compare_line_recursive(left, &mut tmp_string);
+            right.drain(0..1);
+        } else if ! left.starts_with("[") && right.starts_with("[") {
+            right.drain(0..1);
+            let mut tmp_string = format!("{}]", left.chars().next().unwrap());
+            comparison = compare_line_recursive(&mut tmp_string, right);
+            left.drain(0..1);
+        } else {
+            lazy_static! {
+                static ref REGEX_VAL: Regex = Regex::new(r"^(?P<num>[0-9]+)(,)?(?P<remaining>[0-9\s\]\[,]*)$").unwrap();
+            }
+            
+            let left_val: u32 = REGEX_VAL.captures(left).unwrap().name("num").unwrap().as_str().parse().unwrap();
+            *left = String::from(REGEX_VAL.captures(left).unwrap().name("remaining").unwrap().as_str());
+            let right_val: u32 = REGEX_VAL.captures(right).unwrap().name("num").unwrap().as_str().parse().unwrap();
+            *right = String::from(REGEX_VAL.captures(right).unwrap().name("remaining").unwrap().as_str());
+            
+            comparison = match left_val.cmp(&right_val) {
+                Ordering::Less => Comparison::Correct,
+                Ordering::Equal => Comparison::Undetermined,
+                Ordering::Greater => Comparison::Incorrect,
+            };
+        }
+        
+        match comparison {
+            Comparison::Correct => {return Comparison::Correct},
+            Comparison::Incorrect => {return Comparison::Incorrect},
+            Comparison::Undetermined => {continue;},
+        }
+    }
+}
+
+
+// Packets are lists of integers and nested lists, with no whitespace
+fn check_packet(input: &str, line: &str) -> Result<(), ParseError> {
+    if !line.starts_with('[') {
+        return Err(ParseError::new(input, line, "expected a bracketed list"));
+    }
+    let end = check_list(input, line, 0)?;
+    if end < line.len() {
+        return Err(ParseError::new(input, &line[end..], "unexpected text after packet"));
+    }
+    Ok(())
+}
+
+// Checks the list opening at start is elements separated by single commas,
+// each a number or a nested list. Returns the offset just past its ].
+fn check_list(input: &str, line: &str, start: usize) -> Result<usize, ParseError> {
+    let mut i = start + 1;
+    if line[i..].starts_with(']') {
+        return Ok(i + 1);
+    }
+    loop {
+        match line[i..].chars().next() {
+            Some('[') => i = check_list(input, line, i)?,
+            Some('0'..='9') => {
+                let digits = line[i..].find(|c: char| !c.is_ascii_digit()).map_or(line.len(), |length| i + length);
+                parse::number::<u32>(input, &line[i..digits])?;
+                i = digits;
+            },
+            Some(c) => return Err(ParseError::new(input, &line[i..i + c.len_utf8()], "expected a number or list")),
+            None => return Err(ParseError::new(input, &line[start..], "unclosed [")),
+        }
+        match line[i..].chars().next() {
+            Some(',') => i += 1,
+            Some(']') => return Ok(i + 1),
+            Some(c) => return Err(ParseError::new(input, &line[i..i + c.len_utf8()], "expected , or ]")),
+            None => return Err(ParseError::new(input, &line[start..], "unclosed [")),
+        }
+    }
+}
+
+fn compare_line(line1: &str, line2: &str) -> Comparison {
+    let mut left = String::from(line1);
+    let mut right = String::from(line2);
+    
+    compare_line_recursive(&mut left, &mut right)
+}
+
+
+fn parse(input: &str) -> Result<Vec<(String, String)>, ParseError> {
+    let mut pairs = Vec::new();
+    for pair in input.split("\n\n") {
+        let lines: Vec<&str> = pair.lines().collect();
+        if lines.len() != 2 {
+            return Err(ParseError::new(input, pair, "expected a pair of packets"));
+        }
+        check_packet(input, lines[0])?;
+        check_packet(input, lines[1])?;
+        pairs.push((String::from(lines[0]), String::from(lines[1])));
+    }
+    Ok(pairs)
+}
+
+fn compare(pairs: &[(String, String)], log: &Log) -> Result<u32, ProblemError> {
+    let mut score: u32 = 0;
+    for (i, (left, right)) in pairs.iter().enumerate() {
+        let num = i + 1;
+        match compare_line(left, right) {
+            Comparison::Incorrect => {log.trace(|| format!("Pair {num} incorrect")); continue},
+            Comparison::Correct => {log.trace(|| format!("Pair {num} correct")); score += i as u32 + 1;},
+            Comparison::Undetermined => return Err(ProblemError::NoSolution(format!("undetermined order for pair {num}"))),
+        }
+    }
+    Ok(score)
+}
+
+fn divider_packet_index(divider: &str, pairs: &[(String, String)], log: &Log) -> u32 {
+    let mut less_than_count = 0;
+
+    for line in pairs.iter().flat_map(|(left, right)| [left, right]) {
+        match compare_line(line, divider) {
+            Comparison::Correct => {less_than_count += 1},
+            Comparison::Incorrect => {continue},
+            Comparison::Undetermined => {continue},
+        }
+    }
+
+    log.debug(|| format!("{less_than_count} packets before divider {divider}"));
+
+    less_than_count + 1
+}
+
+fn divider_packets(pairs: &[(String, String)], log: &Log) -> u32 {
+    let divider_1 = "[[2]]";
+    let divider_2 = "[[6]]";
+    
+    let divider_1_index = divider_packet_index(divider_1, pairs, log);
+    let divider_2_index = divider_packet_index(divider_2, pairs, log);
+
+    // + 1 divider 2 as it is also greater than divider 1
+    return divider_1_index * (divider_2_index + 1);
+}
+
+
+impl Problem for DayThirteen {
+    type Input = Vec<(String, String)>;
+
+    fn parse(&self, input: &str) -> Result<Vec<(String, String)>, ProblemError> {
+        Ok(parse(input)?)
+    }
+
+    fn part_one(&self, pairs: &Vec<(String, String)>) -> Result<Answer, ProblemError> {
+        let score = compare(pairs, &self.log)?;
+        Ok(Answer::integer("Score", score))
+    }
+
+    fn part_two(&self, pairs: &Vec<(String, String)>) -> Result<Answer, ProblemError> {
+        let score = divider_packets(pairs, &self.log);
+        Ok(Answer::integer("Score", score))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::log::Level;
+
+    const EXAMPLE: &str = include_str!("../../../examples/2022/day_13/example.txt");
+
+    #[test]
+    fn test_packet_cmp_trace() {
+        let day = DayThirteen { log: Log::buffered(Level::Trace) };
+        let _ = day.parse(EXAMPLE).and_then(|input| day.part_one(&input));
+        let messages = day.log.messages();
+        assert_eq!(messages.len(), 8);
+        assert_eq!(messages[0], "trace: Pair 1 correct");
+        assert_eq!(messages[2], "trace: Pair 3 incorrect");
+    }
+
+    #[test]
+    fn test_malformed_packets() {
+        let error = parse("[1,,2]\n[1,3]").unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str(), error.message.as_str()), (1, 4, ",", "expected a number or list"));
+        assert_eq!(parse("[,]\n[1]").unwrap_err().column, 2);
+        assert_eq!(parse("[1,]\n[1]").unwrap_err().column, 4);
+        assert_eq!(parse("[1]\n[[2]").unwrap_err().message, "unclosed [");
+        assert_eq!(parse("[1]]\n[2]").unwrap_err().message, "unexpected text after packet");
+        assert!(parse("[[],[1,[2,10]]]\n[]").is_ok());
+    }
+}
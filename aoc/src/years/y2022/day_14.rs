@@ -1,8 +1,18 @@
-use crate::Problem;
+use crate::parse::{self, ParseError};
+use crate::problem::{Answer, Problem, ProblemError, Registration};
 use std::collections::HashSet;
 
 pub struct DayFourteen;
 
+pub const REGISTRATION: Registration = Registration {
+    year: 2022,
+    day: 14,
+    title: "Regolith Reservoir",
+    parts: &[1, 2],
+    params: &[],
+    new: |_, _| Box::new(DayFourteen{}),
+};
+
 fn simulate_drop(coords: &HashSet<(u32, u32)>, max_y: u32, floor: bool) -> Option<(u32, u32)> {
     let mut coord = (500, 0);
     loop {
@@ -20,14 +30,16 @@ fn simulate_drop(coords: &HashSet<(u32, u32)>, max_y: u32, floor: bool) -> Optio
     }
 }
 
-fn get_walls(input: &str) -> HashSet<(u32, u32)> {
+fn get_walls(input: &str) -> Result<HashSet<(u32, u32)>, ParseError> {
     let mut walls = HashSet::new();
 
     for line in input.lines() {
-        let corners: Vec<(u32, u32)> = line
-            .split(" -> ")
-            .map(|x| (x.split(",").collect::<Vec<&str>>()[0].parse().unwrap(), x.split(",").collect::<Vec<&str>>()[1].parse().unwrap()))
-            .collect();
+        let mut corners: Vec<(u32, u32)> = Vec::new();
+        let corner_strs: Vec<&str> = line.split(" -> ").collect();
+        for corner in &corner_strs {
+            let (x, y) = parse::split_once(input, corner.trim(), ",")?;
+            corners.push((parse::number(input, x)?, parse::number(input, y)?));
+        }
         for i in 0..corners.len()-1 {
             let from: (u32, u32) = corners[i];
             let to: (u32, u32) = corners[i+1];
@@ -53,18 +65,27 @@ fn get_walls(input: &str) -> HashSet<(u32, u32)> {
                     }
                 }
             } else {
-                panic!("From and to don't make sense");
+                return Err(ParseError::new(input, corner_strs[i+1].trim(), "rock paths must be horizontal or vertical"));
             }
         }
     }
+    if walls.is_empty() {
+        return Err(ParseError::new(input, input, "expected at least one rock path"));
+    }
     
-    walls
+    Ok(walls)
 }
 
 
 impl Problem for DayFourteen {
-    fn part_one(&self, input: &str) -> String {
-        let mut coords = get_walls(&input);
+    type Input = HashSet<(u32, u32)>;
+
+    fn parse(&self, input: &str) -> Result<HashSet<(u32, u32)>, ProblemError> {
+        Ok(get_walls(input)?)
+    }
+
+    fn part_one(&self, walls: &HashSet<(u32, u32)>) -> Result<Answer, ProblemError> {
+        let mut coords = walls.clone();
         let max_y: u32 = coords.iter().map(|x| x.1).max().unwrap();
 
         let mut count = 0;
@@ -75,11 +96,11 @@ impl Problem for DayFourteen {
             }
         }
 
-        format!("Number until fall: {count}")
+        Ok(Answer::integer("Number until fall", count))
     }
 
-    fn part_two(&self, input: &str) -> String {
-        let mut coords = get_walls(&input);
+    fn part_two(&self, walls: &HashSet<(u32, u32)>) -> Result<Answer, ProblemError> {
+        let mut coords = walls.clone();
         let max_y: u32 = coords.iter().map(|x| x.1).max().unwrap();
 
         let mut count = 0;
@@ -91,27 +112,6 @@ impl Problem for DayFourteen {
                 break;
             }
         }
-        format!("Number until bottleneck: {count}")
-    }
-}
-
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const INPUT: &str = "498,4 -> 498,6 -> 496,6
-503,4 -> 502,4 -> 502,9 -> 494,9";
-
-    #[test]
-    fn test_sand_drop_p1() {
-        let output = DayFourteen{}.part_one(&INPUT);
-        assert_eq!(output, "Number until fall: 24")
-    }
-
-    #[test]
-    fn test_sand_drop_p2() {
-        let output = DayFourteen{}.part_two(&INPUT);
-        assert_eq!(output, "Number until bottleneck: 93")
+        Ok(Answer::integer("Number until bottleneck", count))
     }
 }
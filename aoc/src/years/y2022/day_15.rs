@@ -0,0 +1,219 @@
+use crate::log::Log;
+use crate::parse::{self, ParseError};
+use crate::problem::{Answer, Param, Params, Problem, ProblemError, Registration};
+use regex::Regex;
+use std::cmp::{min, max};
+
+pub struct DayFifteen {
+    params: Params,
+    log: Log,
+}
+
+pub const REGISTRATION: Registration = Registration {
+    year: 2022,
+    day: 15,
+    title: "Beacon Exclusion Zone",
+    parts: &[1, 2],
+    params: &[
+        Param { name: "line_to_search", default: "2000000", description: "Row to count beacon-free positions in for part one" },
+        Param { name: "extent", default: "4000000", description: "Largest x and y the distress beacon can be at in part two" },
+    ],
+    new: |params, log| Box::new(DayFifteen { params, log }),
+};
+
+struct Sensor {
+    sensor: (i32, i32),
+    nearest_beacon: (i32, i32),
+    distance: u32
+}
+
+impl Sensor {
+    fn get_x_not_possible_range(&self, y: i32, extent: Option<u32>) -> Result<Option<(i32, i32)>, ProblemError> {
+        let y_diff = (y - self.sensor.1).abs() as u32; 
+        if y_diff > self.distance {
+            return Ok(None);
+        }
+        
+        let x_range = self.distance - y_diff;
+        let mut not_possible = ((self.sensor.0 - x_range as i32), (self.sensor.0 + x_range as i32));
+        if self.nearest_beacon.1 == y {
+            if self.nearest_beacon.0 == not_possible.0 {
+                not_possible = (not_possible.0 + 1, not_possible.1);
+            } else if self.nearest_beacon.0 == not_possible.1 {
+                not_possible = (not_possible.0, not_possible.1 - 1);
+            } else {
+                return Err(ProblemError::NoSolution(format!("beacon {:?} should be on the outer edge {:?} of sensor {:?}",
+                                                            self.nearest_beacon, not_possible, self.sensor)));
+            }
+        }
+
+        if let Some(x) = extent {
+            not_possible = (max(0, not_possible.0), min(x as i32, not_possible.1));
+        }
+
+        Ok(Some(not_possible))
+    }
+
+    fn new(sensor: (i32, i32), nearest_beacon: (i32, i32)) -> Sensor {
+        let distance = (sensor.0-nearest_beacon.0).abs() as u32 + (sensor.1-nearest_beacon.1).abs() as u32;
+        
+        Sensor { sensor, nearest_beacon, distance }
+    }
+}
+
+pub struct Map {
+    sensors: Vec<Sensor>,
+}
+
+impl Map {
+    fn merge_ranges(&self, ranges: &mut Vec<(i32, i32)>) -> Vec<(i32, i32)> {
+        ranges.sort_by(|a, b| (a.0).cmp(&b.0));
+        if ranges.is_empty() {
+            return Vec::new();
+        }
+
+        let mut merged = Vec::from([ranges[0]]);
+        for (c, d) in &ranges[1..] {
+            let (a, b) = merged.pop().unwrap(); 
+            if *c <= b + 1 {
+                merged.push((a, max(b, *d)));
+            } else {
+                // No overlap
+                merged.push((a, b));
+                merged.push((*c, *d));
+            }
+        }
+        
+        merged
+    }
+
+    fn get_merged_ranges(&self, y: i32, extent: Option<u32>) -> Result<Vec<(i32, i32)>, ProblemError> {
+        let mut not_possible_ranges: Vec<(i32, i32)> = Vec::new();
+        for sensor in &self.sensors {
+            match sensor.get_x_not_possible_range(y, extent)? {
+                Some(x) => {not_possible_ranges.push(x);}, //@@@ this isn't taking the extent into account
+                None => continue,
+            }
+        }
+
+        Ok(self.merge_ranges(&mut not_possible_ranges))
+    }
+
+    fn count_beacons_not_possible(&self, line_to_search: i32) -> Result<u32, ProblemError> {
+        let merged_ranges = self.get_merged_ranges(line_to_search, None)?;
+
+        let mut total = 0;
+        for (range_from, range_to) in merged_ranges {
+            total += (range_to-range_from + 1) as u32;
+        }
+        Ok(total)
+    }
+
+    fn not_a_beacon_or_sensor(&self, coord: (i32, i32)) -> bool {
+        ! self.sensors.iter().any(|x| x.sensor == coord || x.nearest_beacon == coord)
+    }
+
+
+    fn get_distress_beacon(&self, extent: u32) -> Result<(i32, i32), ProblemError> {
+        for y in 0..extent + 1 {
+            let not_poss = self.get_merged_ranges(y as i32, Some(extent))?;
+            let poss_coord = match not_poss.len() {
+                // The whole row is possible, so the beacon can't be pinned down
+                0 => return Err(ProblemError::NoSolution(format!("no sensor covers row {y}"))),
+                1 => {continue;},
+                2 => (not_poss[0].1 + 1, y as i32),
+                _ => return Err(ProblemError::NoSolution(format!("more than one gap in row {y}"))),
+            };
+            if self.not_a_beacon_or_sensor(poss_coord) {
+                return Ok(poss_coord);
+            }
+        }
+        Err(ProblemError::NoSolution(String::from("didn't find the distress beacon")))
+    }
+
+    fn new(input: &str) -> Result<Map, ParseError> {
+        let mut sensors = Vec::new();
+        // Inputs used to start with the row to search and the extent on lines
+        // of their own. They're parameters now, so say how to pass them.
+        if let Some(header) = input.lines().next().filter(|line| line.trim().parse::<i64>().is_ok()) {
+            return Err(ParseError::new(input, header,
+                "expected a sensor; pass the row and extent as --param line_to_search=N --param extent=N instead of header lines"));
+        }
+        for line in input.lines() {
+            lazy_static! {
+                static ref RE: Regex = Regex::new(r"^Sensor\sat\sx=(?P<x_sensor>[\-0-9]+),\sy=(?P<y_sensor>[\-0-9]+):\sclosest beacon\sis\sat\sx=(?P<x_beacon>[\-0-9]+),\sy=(?P<y_beacon>[\-0-9]+)$").unwrap();
+            }
+        
+            let cap = parse::captures(input, &RE, line.trim(), "a sensor and its closest beacon")?;
+            let x_sensor: i32 = parse::capture(input, &cap, "x_sensor")?;
+            let y_sensor: i32 = parse::capture(input, &cap, "y_sensor")?;
+            let x_beacon: i32 = parse::capture(input, &cap, "x_beacon")?;
+            let y_beacon: i32 = parse::capture(input, &cap, "y_beacon")?;
+
+            let sensor = Sensor::new((x_sensor, y_sensor), (x_beacon, y_beacon));
+            sensors.push(sensor);
+        }
+
+        if sensors.is_empty() {
+            return Err(ParseError::new(input, input, "expected at least one sensor"));
+        }
+
+        Ok(Map { sensors })
+    }
+}
+
+
+
+impl Problem for DayFifteen {
+    type Input = Map;
+
+    fn parse(&self, input: &str) -> Result<Map, ProblemError> {
+        Ok(Map::new(input)?)
+    }
+
+    fn part_one(&self, map: &Map) -> Result<Answer, ProblemError> {
+        let count = map.count_beacons_not_possible(self.params.get("line_to_search")?)?;
+        Ok(Answer::integer("Not possible beacon locations", count))
+    }
+
+    fn part_two(&self, map: &Map) -> Result<Answer, ProblemError> {
+        let distress_beacon = map.get_distress_beacon(self.params.get("extent")?)?;
+        self.log.debug(|| format!("Distress beacon at {distress_beacon:?}"));
+        let tuning_frequency: u64 = distress_beacon.0 as u64 * 4000000 + distress_beacon.1 as u64; 
+        Ok(Answer::integer("Tuning frequency of distress signal", tuning_frequency))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../../examples/2022/day_15/example.txt");
+
+    #[test]
+    fn test_old_header_rejected() {
+        let error = Map::new(&format!("10\n20\n{EXAMPLE}")).err().unwrap();
+        assert_eq!((error.line, error.text.as_str()), (1, "10"));
+        assert!(error.message.contains("--param line_to_search=N"));
+        assert!(Map::new("1").is_err());
+    }
+
+    #[test]
+    fn test_no_sensors() {
+        assert_eq!(Map::new("").err().map(|error| error.message), Some(String::from("expected at least one sensor")));
+    }
+
+    #[test]
+    fn test_uncovered_row() {
+        // Only covers the rows around y=0
+        let map = Map::new("Sensor at x=5, y=0: closest beacon is at x=6, y=0").unwrap();
+        assert_eq!(map.get_distress_beacon(20), Err(ProblemError::NoSolution(String::from("no sensor covers row 2"))));
+    }
+
+    #[test]
+    fn test_beacon_off_edge() {
+        let sensor = Sensor { sensor: (0, 0), nearest_beacon: (1, 0), distance: 3 };
+        assert!(matches!(sensor.get_x_not_possible_range(0, None), Err(ProblemError::NoSolution(_))));
+    }
+}
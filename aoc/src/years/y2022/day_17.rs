@@ -0,0 +1,211 @@
+use crate::parse::ParseError;
+use crate::problem::{Answer, Param, Params, Problem, ProblemError, Registration};
+use std::collections::{HashMap, HashSet};
+use std::cmp::max;
+
+pub struct DaySeventeen {
+    params: Params,
+}
+
+pub const REGISTRATION: Registration = Registration {
+    year: 2022,
+    day: 17,
+    title: "Pyroclastic Flow",
+    parts: &[1, 2],
+    params: &[
+        Param { name: "part_one_rocks", default: "2022", description: "Rocks dropped in part one" },
+        Param { name: "part_two_rocks", default: "1000000000000", description: "Rocks dropped in part two" },
+    ],
+    new: |params, _| Box::new(DaySeventeen { params }),
+};
+
+const CHAMBER_WIDTH: u32 = 7;
+
+const SHAPES: [&'static [(u32, u32)]; 5]= [
+    // ####
+    &[(0,0), (1,0), (2,0), (3,0)],
+    // .#.
+    // ###
+    // .#.
+    &[(1,0), (0,1), (1,1), (2,1), (1,2)],
+    // ..#
+    // ..#
+    // ###
+    &[(0,0), (1,0), (2,0), (2,1), (2,2)],
+    // #
+    // #
+    // #
+    // #
+    &[(0,0), (0,1), (0,2), (0,3)],
+    // ##
+    // ##
+    &[(0,0), (0,1), (1,0), (1,1)],
+];
+
+const SHAPE_COUNT: u32 = 5;
+
+#[derive(Clone, Debug)]
+pub enum Direction {
+    Left,
+    Right
+}
+
+struct Pyroclastic {
+    height: u64,
+    coords: HashSet<(u32, u64)>,
+    directions: Vec<Direction>,
+    direction_index: usize,
+    // Rocks dropped so far, which picks the next shape
+    rocks: u64,
+    // Height of the highest rock in each column
+    tops: [u64; CHAMBER_WIDTH as usize],
+}
+
+// The next shape and jet, and how far each column's top is below the highest
+// rock. Once a state repeats, so does everything after it.
+type State = (usize, usize, [u64; CHAMBER_WIDTH as usize]);
+
+impl Pyroclastic {
+    fn empty_space(&self, shape: &[(u32, u32)], x: u32, y: u64) -> bool {
+        return {
+            ! shape.iter().any(|coord| self.coords.contains(&(coord.0 + x, coord.1 as u64 + y)))
+        }
+    }
+
+    fn drop_rock(&mut self) {
+        let shape = SHAPES[(self.rocks % SHAPE_COUNT as u64) as usize];
+        self.rocks += 1;
+        let mut x = 3;
+        let mut y = self.height + 4;
+        loop {
+            // Move left/right
+            let direction = &self.directions[self.direction_index];
+            self.direction_index = (self.direction_index + 1) % self.directions.len();
+
+            let x_trial: u32 = match direction {
+                Direction::Right => {x + 1},
+                Direction::Left => {x - 1},
+            }; 
+
+
+            if x_trial > 0 && ! shape.iter().any(|coord| coord.0 + x_trial > CHAMBER_WIDTH)
+                && self.empty_space(shape, x_trial, y) {
+                x = x_trial;
+            }
+
+            // Move down
+            if y > 1 && self.empty_space(shape, x, y - 1) {
+                y -= 1;
+            } else {
+                for coord in shape {
+                    let (coord_x, coord_y) = (coord.0 + x, coord.1 as u64 + y);
+                    self.coords.insert((coord_x, coord_y));
+                    self.height = max(self.height, coord_y);
+                    // Columns are numbered from 1, inside the left wall
+                    let top = &mut self.tops[coord_x as usize - 1];
+                    *top = max(*top, coord_y);
+                }
+                return;
+            }
+        }
+    }
+
+    fn drop_rocks(&mut self, count: u64) {
+        for _ in 0..count {
+            self.drop_rock();
+        }
+    }
+
+    fn state(&self) -> State {
+        ((self.rocks % SHAPE_COUNT as u64) as usize, self.direction_index, self.tops.map(|top| self.height - top))
+    }
+
+    // Simulates until the state repeats, then skips ahead by whole cycles and
+    // simulates the rocks left over. Without a repeat every rock is simulated.
+    fn height_after(&mut self, rocks: u64) -> u64 {
+        let mut seen: HashMap<State, (u64, u64)> = HashMap::new();
+        while self.rocks < rocks {
+            if let Some((cycle_start, start_height)) = seen.insert(self.state(), (self.rocks, self.height)) {
+                let (cycle, cycle_height) = (self.rocks - cycle_start, self.height - start_height);
+                let cycles = (rocks - self.rocks) / cycle;
+                self.drop_rocks((rocks - self.rocks) % cycle);
+                return self.height + cycles * cycle_height;
+            }
+            self.drop_rock();
+        }
+        self.height
+    }
+
+    fn new(directions: Vec<Direction>) -> Pyroclastic { 
+        let coords: HashSet<(u32, u64)> = HashSet::new();
+        let height = 0;
+
+        Pyroclastic { height, coords, directions, direction_index: 0, rocks: 0, tops: [0; CHAMBER_WIDTH as usize] }
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Direction>, ParseError> {
+    let mut directions: Vec<Direction> = Vec::new();
+    for (i, direction) in input.char_indices() {
+        directions.push(
+            match direction {
+                '>' => Direction::Right,
+                '<' => Direction::Left,
+                _ => return Err(ParseError::new(input, &input[i..i + direction.len_utf8()], "expected < or >"))
+            }
+        )
+    }
+    if directions.is_empty() {
+        return Err(ParseError::new(input, input, "expected at least one jet direction"));
+    }
+
+    Ok(directions)
+}
+
+
+impl Problem for DaySeventeen {
+    type Input = Vec<Direction>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Direction>, ProblemError> {
+        Ok(parse(input)?)
+    }
+
+    fn part_one(&self, directions: &Vec<Direction>) -> Result<Answer, ProblemError> {
+        let mut pyroclastic = Pyroclastic::new(directions.clone());
+        pyroclastic.drop_rocks(self.params.get("part_one_rocks")?);
+        let height = pyroclastic.height;
+        Ok(Answer::integer("Height", height))
+    }
+
+    fn part_two(&self, directions: &Vec<Direction>) -> Result<Answer, ProblemError> {
+        let mut pyroclastic = Pyroclastic::new(directions.clone());
+        let height = pyroclastic.height_after(self.params.get("part_two_rocks")?);
+        Ok(Answer::integer("Height", height))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../../examples/2022/day_17/example.txt");
+
+    #[test]
+    fn test_p2_fewer_rocks_than_cycle() {
+        let overrides = [(String::from("part_one_rocks"), String::from("5000")), (String::from("part_two_rocks"), String::from("5000"))];
+        let day = DaySeventeen { params: Params::new(REGISTRATION.params, &overrides) };
+        let directions = day.parse(EXAMPLE.trim_end()).unwrap();
+        assert_eq!(day.part_two(&directions), day.part_one(&directions));
+    }
+
+    #[test]
+    fn test_cycle_skips_match_simulation() {
+        let directions = parse(EXAMPLE.trim_end()).unwrap();
+        for rocks in [2022, 3001, 4567] {
+            let mut simulated = Pyroclastic::new(directions.clone());
+            simulated.drop_rocks(rocks);
+            assert_eq!(Pyroclastic::new(directions.clone()).height_after(rocks), simulated.height);
+        }
+    }
+}
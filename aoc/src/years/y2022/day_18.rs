@@ -1,10 +1,20 @@
-use crate::Problem;
+use crate::parse::{self, ParseError};
+use crate::problem::{Answer, Problem, ProblemError, Registration};
 use std::collections::{HashSet, VecDeque};
 
 pub struct DayEighteen;
 
+pub const REGISTRATION: Registration = Registration {
+    year: 2022,
+    day: 18,
+    title: "Boiling Boulders",
+    parts: &[1, 2],
+    params: &[],
+    new: |_, _| Box::new(DayEighteen{}),
+};
 
-struct Lava {
+
+pub struct Lava {
     droplets: HashSet<(i32,i32,i32)>,
     x_extent: (i32, i32),
     y_extent: (i32, i32),
@@ -87,72 +97,45 @@ impl Lava {
         self._get_surface_area(&self.droplets)
     }
 
-    fn new(input: &str) -> Lava {
+    fn new(input: &str) -> Result<Lava, ParseError> {
         let mut droplets = HashSet::new();
 
         for line in input.lines() {
-            let coords: Vec<i32> = line.trim().split(",").map(|x| x.parse().unwrap()).collect();
+            let mut coords: Vec<i32> = Vec::new();
+            for x in line.trim().split(",") {
+                coords.push(parse::number(input, x)?);
+            }
+            if coords.len() != 3 {
+                return Err(ParseError::new(input, line.trim(), "expected x,y,z coordinates"));
+            }
             droplets.insert((coords[0], coords[1], coords[2]));
         }
+        if droplets.is_empty() {
+            return Err(ParseError::new(input, input, "expected at least one droplet"));
+        }
 
         let x_extent = (droplets.iter().map(|x| x.0).min().unwrap() - 1, droplets.iter().map(|x| x.0).max().unwrap() + 1);
         let y_extent = (droplets.iter().map(|x| x.1).min().unwrap() - 1, droplets.iter().map(|x| x.1).max().unwrap() + 1);
         let z_extent = (droplets.iter().map(|x| x.2).min().unwrap() - 1, droplets.iter().map(|x| x.2).max().unwrap() + 1);
 
-        Lava { droplets, x_extent, y_extent, z_extent }
+        Ok(Lava { droplets, x_extent, y_extent, z_extent })
     }
 }
 
 impl Problem for DayEighteen {
-    fn part_one(&self, input: &str) -> String {
-        let lava = Lava::new(&input);
-        let area = lava.get_surface_area();
-        format!("Surface area: {area}")
-    }
+    type Input = Lava;
 
-    fn part_two(&self, input: &str) -> String {
-        let lava = Lava::new(&input);
-        let exterior_area = lava.get_surface_area() - lava.get_interior_area();
-        format!("Outer surface area: {exterior_area}")
-    }
-}
-
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const INPUT: &str = "2,2,2
-1,2,2
-3,2,2
-2,1,2
-2,3,2
-2,2,1
-2,2,3
-2,2,4
-2,2,6
-1,2,5
-3,2,5
-2,1,5
-2,3,5";
-
-    #[test]
-    fn test_lava_simple() {
-        let input = "1,1,1
-2,1,1";
-        let output = DayEighteen{}.part_one(&input);
-        assert_eq!(output, "Surface area: 10")
+    fn parse(&self, input: &str) -> Result<Lava, ProblemError> {
+        Ok(Lava::new(input)?)
     }
 
-    #[test]
-    fn test_lava_complex() {
-        let output = DayEighteen{}.part_one(&INPUT);
-        assert_eq!(output, "Surface area: 64")
+    fn part_one(&self, lava: &Lava) -> Result<Answer, ProblemError> {
+        let area = lava.get_surface_area();
+        Ok(Answer::integer("Surface area", area))
     }
 
-    #[test]
-    fn test_lava_exterior_area() {
-        let output = DayEighteen{}.part_two(&INPUT);
-        assert_eq!(output, "Outer surface area: 58")
+    fn part_two(&self, lava: &Lava) -> Result<Answer, ProblemError> {
+        let exterior_area = lava.get_surface_area() - lava.get_interior_area();
+        Ok(Answer::integer("Outer surface area", exterior_area))
     }
 }
@@ -1,11 +1,26 @@
-use crate::Problem;
+use crate::parse::{self, ParseError};
+use crate::problem::{Answer, Param, Params, Problem, ProblemError, Registration};
 use regex::Regex;
 use std::collections::HashMap;
 use std::cmp::max;
 
-pub struct DayNineteen;
+pub struct DayNineteen {
+    params: Params,
+}
 
-#[derive(Debug)]
+pub const REGISTRATION: Registration = Registration {
+    year: 2022,
+    day: 19,
+    title: "Not Enough Minerals",
+    parts: &[1, 2],
+    params: &[
+        Param { name: "part_one_minutes", default: "24", description: "Minutes to collect geodes for in part one" },
+        Param { name: "part_two_minutes", default: "32", description: "Minutes to collect geodes for in part two" },
+    ],
+    new: |params, _| Box::new(DayNineteen { params }),
+};
+
+#[derive(Clone, Debug)]
 struct Recipe {
     ore: u32, 
     clay: u32,
@@ -18,6 +33,7 @@ impl Recipe {
     }
 }
 
+#[derive(Clone)]
 struct Blueprint {
     blueprint_number: u8,
     ore_robot: Recipe,
@@ -25,6 +41,7 @@ struct Blueprint {
     obsidian_robot: Recipe,
     geode_robot: Recipe,
     memoize: HashMap<(u32, u32, u32, u32, u32, u32, u32, u32, u32), u32>,
+    // Time limit of the search in progress, set by search()
     minutes: u32
 }
 
@@ -145,89 +162,98 @@ impl Blueprint {
         max
     }
 
-    pub fn quality_level(&mut self) -> u32 {
-        let geodes = self.choose_route(1, 0, 0, 0, 0, 1, 0, 0, 0);
+    // Searches on a copy with an empty memo, since the memo depends on the time limit
+    fn search(&self, minutes: u32) -> u32 {
+        let mut blueprint = Blueprint { memoize: HashMap::new(), minutes, ..self.clone() };
+        blueprint.choose_route(1, 0, 0, 0, 0, 1, 0, 0, 0)
+    }
+
+    pub fn quality_level(&self, minutes: u32) -> u32 {
+        let geodes = self.search(minutes);
         geodes as u32 * self.blueprint_number as u32
     }
 
-    pub fn max_geodes(&mut self) -> u32 {
-        let geodes = self.choose_route(1, 0, 0, 0, 0, 1, 0, 0, 0);
+    pub fn max_geodes(&self, minutes: u32) -> u32 {
+        let geodes = self.search(minutes);
         geodes as u32
     }
 
-    pub fn new(input: &str, minutes: u32) -> Blueprint {
+    pub fn new(input: &str, blueprint: &str) -> Result<Blueprint, ParseError> {
         lazy_static! {
             static ref RE: Regex = Regex::new(r"^Blueprint (?P<blueprint_number>[0-9]+): Each ore robot costs (?P<ore_ore>[0-9]+) ore. Each clay robot costs (?P<clay_ore>[0-9]+) ore. Each obsidian robot costs (?P<obsidian_ore>[0-9]+) ore and (?P<obsidian_clay>[0-9]+) clay. Each geode robot costs (?P<geode_ore>[0-9]+) ore and (?P<geode_obsidian>[0-9]+) obsidian.$").unwrap();
         }
     
-        let cap = RE.captures(input.trim()).unwrap();
-        let blueprint_number: u8 = cap.name("blueprint_number").unwrap().as_str().parse::<u8>().unwrap();
-        let ore_ore: u32 = cap.name("ore_ore").unwrap().as_str().parse::<u32>().unwrap();
+        let cap = parse::captures(input, &RE, blueprint.trim(), "a blueprint")?;
+        let blueprint_number: u8 = parse::capture(input, &cap, "blueprint_number")?;
+        let ore_ore: u32 = parse::capture(input, &cap, "ore_ore")?;
         let ore_robot = Recipe::new(ore_ore, 0, 0);
 
-        let clay_ore: u32 = cap.name("clay_ore").unwrap().as_str().parse::<u32>().unwrap();
+        let clay_ore: u32 = parse::capture(input, &cap, "clay_ore")?;
         let clay_robot = Recipe::new(clay_ore, 0, 0);
 
-        let obsidian_ore: u32 = cap.name("obsidian_ore").unwrap().as_str().parse::<u32>().unwrap();
-        let obsidian_clay: u32 = cap.name("obsidian_clay").unwrap().as_str().parse::<u32>().unwrap();
+        let obsidian_ore: u32 = parse::capture(input, &cap, "obsidian_ore")?;
+        let obsidian_clay: u32 = parse::capture(input, &cap, "obsidian_clay")?;
         let obsidian_robot = Recipe::new(obsidian_ore, obsidian_clay, 0);
 
-        let geode_ore: u32 = cap.name("geode_ore").unwrap().as_str().parse::<u32>().unwrap();
-        let geode_obsidian: u32 = cap.name("geode_obsidian").unwrap().as_str().parse::<u32>().unwrap();
+        let geode_ore: u32 = parse::capture(input, &cap, "geode_ore")?;
+        let geode_obsidian: u32 = parse::capture(input, &cap, "geode_obsidian")?;
         let geode_robot = Recipe::new(geode_ore, 0, geode_obsidian);
 
         let memoize = HashMap::new();
 
-        Blueprint { blueprint_number, ore_robot, clay_robot, obsidian_robot, geode_robot, memoize, minutes }
+        Ok(Blueprint { blueprint_number, ore_robot, clay_robot, obsidian_robot, geode_robot, memoize, minutes: 0 })
     }
 }
 
-struct Blueprints {
+pub struct Blueprints {
     blueprints: Vec<Blueprint>
 }
 
 impl Blueprints {
-    fn get_quality_score(&mut self) -> u32 {
+    fn get_quality_score(&self, minutes: u32) -> u32 {
         let mut score: u32 = 0;
-        for blueprint in &mut self.blueprints {
-            score += blueprint.quality_level() as u32;
+        for blueprint in &self.blueprints {
+            score += blueprint.quality_level(minutes) as u32;
         } 
 
         score
     }
 
-    fn get_score_product(&mut self) -> u32 {
+    fn get_score_product(&self, minutes: u32) -> u32 {
         let mut score: u32 = 1;
-        for i in 0..3 {
-            let blueprint = &mut self.blueprints[i];
-            score *= blueprint.max_geodes();
+        for blueprint in self.blueprints.iter().take(3) {
+            score *= blueprint.max_geodes(minutes);
         }
 
         score
     }
 
-    fn new(input: &str, minutes: u32) -> Blueprints {
+    fn new(input: &str) -> Result<Blueprints, ParseError> {
         let mut blueprints = Vec::new();
 
         for blueprint in input.lines() {
-            blueprints.push(Blueprint::new(blueprint, minutes))
+            blueprints.push(Blueprint::new(input, blueprint)?)
         }
 
-        Blueprints{ blueprints }
+        Ok(Blueprints{ blueprints })
     }
 }
 
 impl Problem for DayNineteen {
-    fn part_one(&self, input: &str) -> String {
-        let mut robots = Blueprints::new(&input, 24);
-        let score = robots.get_quality_score();
-        format!("Quality levels: {score}")
+    type Input = Blueprints;
+
+    fn parse(&self, input: &str) -> Result<Blueprints, ProblemError> {
+        Ok(Blueprints::new(input)?)
+    }
+
+    fn part_one(&self, robots: &Blueprints) -> Result<Answer, ProblemError> {
+        let score = robots.get_quality_score(self.params.get("part_one_minutes")?);
+        Ok(Answer::integer("Quality levels", score))
     }
 
-    fn part_two(&self, input: &str) -> String {
-        let mut robots = Blueprints::new(&input, 32);
-        let score = robots.get_score_product();
-        format!("Quality levels: {score}")
+    fn part_two(&self, robots: &Blueprints) -> Result<Answer, ProblemError> {
+        let score = robots.get_score_product(self.params.get("part_two_minutes")?);
+        Ok(Answer::integer("Quality levels", score))
     }
 }
 
@@ -236,28 +262,19 @@ impl Problem for DayNineteen {
 mod tests {
     use super::*;
 
-    const INPUT: &str = "Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. Each obsidian robot costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 obsidian.
-Blueprint 2: Each ore robot costs 2 ore. Each clay robot costs 3 ore. Each obsidian robot costs 3 ore and 8 clay. Each geode robot costs 3 ore and 12 obsidian.";
-
-    #[test]
-    fn test_geodes_p1() {
-        let output = DayNineteen{}.part_one(&INPUT);
-        assert_eq!(output, "Quality levels: 33")
-    }
-
     #[test]
     fn test_geodes_p2_blueprint_1() {
         let input = "Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. Each obsidian robot costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 obsidian.";
 
-        let mut blueprint = Blueprint::new(&input, 32);
-        assert_eq!(blueprint.max_geodes(), 56);
+        let blueprint = Blueprint::new(&input, &input).unwrap();
+        assert_eq!(blueprint.max_geodes(32), 56);
     }
 
     #[test]
     fn test_geodes_p2_blueprint_2() {
         let input = "Blueprint 2: Each ore robot costs 2 ore. Each clay robot costs 3 ore. Each obsidian robot costs 3 ore and 8 clay. Each geode robot costs 3 ore and 12 obsidian.";
         
-        let mut blueprint = Blueprint::new(&input, 32);
-        assert_eq!(blueprint.max_geodes(), 62);
+        let blueprint = Blueprint::new(&input, &input).unwrap();
+        assert_eq!(blueprint.max_geodes(32), 62);
     }
 }
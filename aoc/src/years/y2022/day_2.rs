@@ -0,0 +1,131 @@
+use crate::parse::ParseError;
+use crate::problem::{Answer, Problem, ProblemError, Registration};
+
+pub struct DayTwo {}
+
+pub const REGISTRATION: Registration = Registration {
+    year: 2022,
+    day: 2,
+    title: "Rock Paper Scissors",
+    parts: &[1, 2],
+    params: &[],
+    new: |_, _| Box::new(DayTwo{}),
+};
+
+fn modulo(val: isize, modulus: isize) -> u8 {
+    (((val % modulus) + modulus) % modulus) as u8
+}
+    
+
+fn comparison_score(opponent: u8, player: u8) -> u32 {
+    let comparison = modulo(player as isize - opponent as isize, 3);
+    match comparison {
+        0 => 3, // draw
+        1 => 6, // win
+        2 => 0, // loss 
+        _ => panic!("Unknown round outcome: {}", comparison)
+    }
+}
+
+fn player_score(player: u8) -> u32 {
+    match player {
+        1 => 1, 
+        2 => 2,
+        3 => 3,
+        _ => panic!("Unknown player type: {}", player)
+    } 
+}
+
+fn player_as_action(_opponent: u8, player: u8) -> u8 {
+    player
+}
+
+fn player_as_result(opponent: u8, player: u8) -> u8 {
+    match player {
+        1 => modulo(opponent as isize - 2, 3) + 1, // lose
+        2 => opponent, // draw
+        3 => modulo(opponent as isize, 3) + 1, // win
+        _ => panic!("Unknown player column: {}", player)
+    }
+}
+
+// Each round is the opponent's shape and the player's column, both 1 to 3.
+// What the column means depends on the part.
+fn parse(input: &str) -> Result<Vec<(u8, u8)>, ParseError> {
+    let mut rounds = Vec::new();
+    for line in input.split("\n") {
+        if line == "" {
+            continue
+        }
+        let opponent_player: Vec<&str> = line.trim().split(" ").collect();
+        if opponent_player.len() != 2 {
+            return Err(ParseError::new(input, line.trim(), "expected an opponent and a player"));
+        }
+        let opponent = match opponent_player[0] {
+            "A" => 1,
+            "B" => 2,
+            "C" => 3,
+            &_ => return Err(ParseError::new(input, opponent_player[0], "opponent not recognised"))
+        };
+        let player = match opponent_player[1] {
+            "X" => 1,
+            "Y" => 2,
+            "Z" => 3,
+            &_ => return Err(ParseError::new(input, opponent_player[1], "player not recognised"))
+        };
+
+        rounds.push((opponent, player));
+    }
+
+    return Ok(rounds)
+}
+
+fn total_score<F: Fn(u8, u8) -> u8>(rounds: &[(u8, u8)], player_infer: F) -> u32 {
+    rounds.iter().map(|&(opponent, player)| {
+        let player = player_infer(opponent, player);
+        comparison_score(opponent, player) + player_score(player)
+    }).sum()
+}
+
+
+impl Problem for DayTwo {
+    type Input = Vec<(u8, u8)>;
+
+    fn parse(&self, input: &str) -> Result<Vec<(u8, u8)>, ProblemError> {
+        Ok(parse(input)?)
+    }
+
+    fn part_one(&self, rounds: &Vec<(u8, u8)>) -> Result<Answer, ProblemError> {
+        let total = total_score(rounds, player_as_action);
+        Ok(Answer::integer("Total score", total))
+    }
+
+    fn part_two(&self, rounds: &Vec<(u8, u8)>) -> Result<Answer, ProblemError> {
+        let total = total_score(rounds, player_as_result);
+        Ok(Answer::integer("Total score", total))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../../examples/2022/day_2/example.txt");
+
+    #[test]
+    fn find_scores_pt1() {
+        let rounds = parse(EXAMPLE).unwrap();
+        assert_eq!(rounds, vec![(1, 2), (2, 1), (3, 3)]);
+        let result: Vec<u32> = rounds.iter().map(|round| total_score(&[*round], player_as_action)).collect();
+        assert_eq!(result, vec![8, 1, 6]);
+    }
+
+    #[test]
+    fn find_scores_pt2() {
+        let rounds = parse(EXAMPLE).unwrap();
+        assert_eq!(rounds, vec![(1, 2), (2, 1), (3, 3)]);
+        let result: Vec<u32> = rounds.iter().map(|round| total_score(&[*round], player_as_result)).collect();
+        assert_eq!(result, vec![4, 1, 7]);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,90 @@
+use crate::parse::{self, ParseError};
+use crate::problem::{Answer, Param, Params, Problem, ProblemError, Registration};
+use std::collections::VecDeque;
+
+pub struct DayTwenty {
+    params: Params,
+}
+
+pub const REGISTRATION: Registration = Registration {
+    year: 2022,
+    day: 20,
+    title: "Grove Positioning System",
+    parts: &[1, 2],
+    params: &[
+        Param { name: "decryption_key", default: "811589153", description: "Key each number is multiplied by in part two" },
+    ],
+    new: |params, _| Box::new(DayTwenty { params }),
+};
+
+struct Cipher {
+    content: VecDeque<(i64, usize)>
+}
+
+impl Cipher {   
+    fn decrypt(&mut self, run_count: u32) {
+        for _ in 0..run_count {
+            for i in 0..self.content.len() {
+                let index = self.content.iter().position(|&r| r.1 == i).unwrap();
+                let element = self.content.remove(index).unwrap();
+                let new_index = (index as i64 + element.0).rem_euclid(self.content.len() as i64) as usize;
+                self.content.insert(new_index, element)
+            }
+        }
+    }
+
+    fn get_sum(&self) -> Option<i64> {
+        let zero_index = self.content.iter().position(|&r| r.0 == 0)?;
+        
+        let mut sum = 0;
+        for index in [1000, 2000, 3000] {
+            sum += self.content.get((zero_index + index) % self.content.len()).unwrap().0;
+        }
+
+        Some(sum as i64)
+    }
+
+    pub fn new(numbers: &[i64], decryption_key: i64) -> Cipher {
+        let mut content = VecDeque::new();
+        for (i, num) in numbers.iter().enumerate() {
+            content.push_back(((num * decryption_key), i));
+        }
+
+        Cipher { content }
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+    let mut numbers = Vec::new();
+    for numstr in input.lines() {
+        numbers.push(parse::number(input, numstr.trim())?);
+    }
+    if numbers.len() < 2 {
+        return Err(ParseError::new(input, input, "expected at least two numbers"));
+    }
+
+    Ok(numbers)
+}
+
+
+impl Problem for DayTwenty {
+    type Input = Vec<i64>;
+
+    fn parse(&self, input: &str) -> Result<Vec<i64>, ProblemError> {
+        Ok(parse(input)?)
+    }
+
+    fn part_one(&self, numbers: &Vec<i64>) -> Result<Answer, ProblemError> {
+        let mut cipher = Cipher::new(numbers, 1);
+        cipher.decrypt(1);
+        let sum = cipher.get_sum().ok_or(ProblemError::NoSolution(String::from("no 0 in the file")))?;
+        Ok(Answer::integer("Sum", sum))
+    }
+
+    fn part_two(&self, numbers: &Vec<i64>) -> Result<Answer, ProblemError> {
+        let mut cipher = Cipher::new(numbers, self.params.get("decryption_key")?);
+        cipher.decrypt(10);
+        let sum = cipher.get_sum().ok_or(ProblemError::NoSolution(String::from("no 0 in the file")))?;
+        Ok(Answer::integer("Sum", sum))
+    }
+}
@@ -1,9 +1,19 @@
-use crate::Problem;
+use crate::parse::{self, ParseError};
+use crate::problem::{Answer, Problem, ProblemError, Registration};
 use std::collections::HashMap;
 use regex::Regex;
 
 pub struct DayTwentyOne;
 
+pub const REGISTRATION: Registration = Registration {
+    year: 2022,
+    day: 21,
+    title: "Monkey Math",
+    parts: &[1, 2],
+    params: &[],
+    new: |_, _| Box::new(DayTwentyOne{}),
+};
+
 enum Operation {
     Add,
     Subtract,
@@ -18,16 +28,16 @@ struct Monkey {
 }
 
 impl Monkey {
-    fn new(input: &str) -> Monkey {
+    fn new(input: &str, line: &str) -> Result<Monkey, ParseError> {
         lazy_static! {
             static ref RE: Regex = Regex::new(r"^(?P<name>[a-z]+):\s(((?P<name1>[a-z]+)\s(?P<operator>[+\-*/])\s(?P<name2>[a-z]+))|(?P<number>[0-9]+))$").unwrap();
         }
-        let cap = RE.captures(input.trim()).unwrap();
+        let cap = parse::captures(input, &RE, line.trim(), "a monkey name and job")?;
 
         let name: String = String::from(cap.name("name").unwrap().as_str());
-        if let Some(x) = cap.name("number") {
-            let number = Some(x.as_str().parse().unwrap());
-            return Monkey { name: name.to_string(), sum: None, number }
+        if cap.name("number").is_some() {
+            let number = Some(parse::capture(input, &cap, "number")?);
+            return Ok(Monkey { name: name.to_string(), sum: None, number })
         } else {
             let name1: String = String::from(cap.name("name1").unwrap().as_str());
             let name2: String = String::from(cap.name("name2").unwrap().as_str());
@@ -36,18 +46,17 @@ impl Monkey {
                 "+" => Operation::Add,
                 "-" => Operation::Subtract,
                 "*" => Operation::Multiply,
-                "/" => Operation::Divide,
-                _ => panic!("Unrecognised {operator_str}")
+                _ => Operation::Divide,
             };
 
             let sum = Some((name1, name2, operator));
 
-            return Monkey { name, sum, number: None };
+            return Ok(Monkey { name, sum, number: None });
         }
     }
 }
 
-struct Monkeys {
+pub struct Monkeys {
     monkeys: HashMap<String, Monkey>
 }
 
@@ -102,21 +111,21 @@ impl Monkeys {
         panic!("humn not found");
     }   
 
-    fn find_humn_root_equal(&self) -> u64 {
+    fn find_humn_root_equal(&self) -> Option<u64> {
         let root = self.monkeys.get(&String::from("root")).unwrap();
         
         if let Some(x) = &root.sum {
             let (monkey_a, monkey_b, _) = x;
             if self.contains_humn(&monkey_a) {
                 let value = self.get_value(&monkey_b);
-                return self.find_humn_value(value, &monkey_a);
+                return Some(self.find_humn_value(value, &monkey_a));
             } else if self.contains_humn(&monkey_b) {
                 let value = self.get_value(&monkey_a);
-                return self.find_humn_value(value, &monkey_b);
+                return Some(self.find_humn_value(value, &monkey_b));
             }
         }
 
-        panic!("humn not found");
+        None
     }
 
     fn get_value(&self, name: &String) -> u64 {
@@ -143,63 +152,48 @@ impl Monkeys {
         panic!("Couldn't find answer or sum on monkey");
     }
 
-    fn new(input: &str) -> Monkeys {
+    fn new(input: &str) -> Result<Monkeys, ParseError> {
         let mut monkeys = HashMap::new();
+        let mut lines = HashMap::new();
 
         for line in input.lines() {
-            let monkey = Monkey::new(line);
+            let monkey = Monkey::new(input, line)?;
+            lines.insert(monkey.name.clone(), line);
             monkeys.insert(monkey.name.clone(), monkey);
         }
 
-        Monkeys { monkeys }
+        // Every monkey waited on must exist, including root
+        for monkey in monkeys.values() {
+            if let Some((name1, name2, _)) = &monkey.sum {
+                if let Some(missing) = [name1, name2].into_iter().find(|name| !monkeys.contains_key(*name)) {
+                    return Err(ParseError::new(input, lines[&monkey.name].trim(), &format!("waits on missing monkey {missing}")));
+                }
+            }
+        }
+        if !monkeys.contains_key("root") {
+            return Err(ParseError::new(input, input, "no root monkey"));
+        }
+
+        Ok(Monkeys { monkeys })
     }
 }
 
 
 impl Problem for DayTwentyOne {
-    fn part_one(&self, input: &str) -> String {
-        let monkeys = Monkeys::new(&input);
-        let root = monkeys.get_value(&String::from("root"));
-        format!("Root number: {root}")
-    }
+    type Input = Monkeys;
 
-    fn part_two(&self, input: &str) -> String {
-        let monkeys = Monkeys::new(&input);
-        let humn = monkeys.find_humn_root_equal();
-        format!("humn number: {humn}")
+    fn parse(&self, input: &str) -> Result<Monkeys, ProblemError> {
+        Ok(Monkeys::new(input)?)
     }
-}
-
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const INPUT: &str = "root: pppw + sjmn
-dbpl: 5
-cczh: sllz + lgvd
-zczc: 2
-ptdq: humn - dvpt
-dvpt: 3
-lfqf: 4
-humn: 5
-ljgn: 2
-sjmn: drzm * dbpl
-sllz: 4
-pppw: cczh / lfqf
-lgvd: ljgn * ptdq
-drzm: hmdt - zczc
-hmdt: 32";
-
-    #[test]
-    fn test_monkeys_p1() {
-        let output = DayTwentyOne{}.part_one(&INPUT);
-        assert_eq!(output, "Root number: 152")
+    fn part_one(&self, monkeys: &Monkeys) -> Result<Answer, ProblemError> {
+        let root = monkeys.get_value(&String::from("root"));
+        Ok(Answer::integer("Root number", root))
     }
 
-    #[test]
-    fn test_monkeys_p2() {
-        let output = DayTwentyOne{}.part_two(&INPUT);
-        assert_eq!(output, "humn number: 301")
+    fn part_two(&self, monkeys: &Monkeys) -> Result<Answer, ProblemError> {
+        let humn = monkeys.find_humn_root_equal()
+            .ok_or(ProblemError::NoSolution(String::from("humn not found under root")))?;
+        Ok(Answer::integer("humn number", humn))
     }
 }
@@ -1,9 +1,19 @@
-use crate::Problem;
+use crate::parse::{self, ParseError};
+use crate::problem::{Answer, Problem, ProblemError, Registration};
 use regex::Regex;
 
 pub struct DayTwentyTwo;
 
-#[derive(Debug)]
+pub const REGISTRATION: Registration = Registration {
+    year: 2022,
+    day: 22,
+    title: "Monkey Map",
+    parts: &[1],
+    params: &[],
+    new: |_, _| Box::new(DayTwentyTwo{}),
+};
+
+#[derive(Clone, Debug)]
 enum Direction {
     Up,
     Down,
@@ -40,14 +50,15 @@ impl Direction {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 enum Square {
     Open,
     Wall,
     Empty
 }
 
-struct Map {
+#[derive(Clone)]
+pub struct Map {
     current_square: (usize, usize),
     current_direction: Direction,
     rows: Vec<Vec<Square>>,
@@ -96,90 +107,72 @@ impl Map {
         }
     }
 
-    fn new(input: &str) -> Map {
-        let map_instructions: Vec<&str> = input.split("\n\n").collect();
+    fn new(input: &str) -> Result<Map, ParseError> {
+        let (map_str, instructions_str) = parse::split_once(input, input, "\n\n")?;
 
-        let row_count = map_instructions[0].lines().collect::<Vec<&str>>().len();
-        let col_count = map_instructions[0].lines().map(|x| x.len()).max().unwrap();
+        let row_count = map_str.lines().collect::<Vec<&str>>().len();
+        let col_count = map_str.lines().map(|x| x.len()).max().unwrap_or(0);
 
         let mut rows = Vec::new();
-        for line in map_instructions[0].lines() {
-            let chars: Vec<char> = line.chars().collect(); 
+        for line in map_str.lines() {
             let mut row = Vec::new();
-            for i in 0..col_count {
-                if i >= chars.len() {
-                    row.push(Square::Empty);
-                } else {
-                    row.push(
-                        match chars[i] {
-                            ' ' => Square::Empty,
-                            '.' => Square::Open,
-                            '#' => Square::Wall,
-                            _ => panic!("Unrecognised")
-                        }
-                    );
-                }
+            for (i, c) in line.char_indices() {
+                row.push(
+                    match c {
+                        ' ' => Square::Empty,
+                        '.' => Square::Open,
+                        '#' => Square::Wall,
+                        _ => return Err(ParseError::new(input, &line[i..i + c.len_utf8()], "expected a tile: space, . or #"))
+                    }
+                );
+            }
+            while row.len() < col_count {
+                row.push(Square::Empty);
             }
             rows.push(row);
         }
-        let current_square = (rows[0].iter().position(|x| matches!(x, Square::Open)).unwrap(), 0);
-
+        let first_open = rows.first().and_then(|row| row.iter().position(|x| matches!(x, Square::Open)));
+        let current_square = match first_open {
+            Some(column) => (column, 0),
+            None => return Err(ParseError::new(input, map_str, "no open tile on the first row")),
+        };
+
+        // Instructions are a distance to walk followed by turns and distances.
+        // Starting facing up, the first distance is treated as turning right.
+        let instructions_str = instructions_str.trim();
+        if let Some((i, c)) = instructions_str.char_indices().find(|(_, c)| !(c.is_ascii_digit() || *c == 'L' || *c == 'R')) {
+            return Err(ParseError::new(input, &instructions_str[i..i + c.len_utf8()], "expected a distance or a turn L or R"));
+        }
         let mut instructions = Vec::new();
-        let re = Regex::new(r"(?P<lr>[LR])(?P<number>[0-9]+)").unwrap();
-        for cap in re.captures_iter(&format!("R{}", map_instructions[1])[..]) {
-            let clockwise = match cap.name("lr").unwrap().as_str() {
-                "L" => false,
-                "R" => true,
-                _ => panic!("Don't recognise")
-            };
-
-            instructions.push((clockwise, cap.name("number").unwrap().as_str().parse().unwrap()));
+        let re = Regex::new(r"(?P<lr>[LR]?)(?P<number>[0-9]+)").unwrap();
+        for cap in re.captures_iter(instructions_str) {
+            let clockwise = cap.name("lr").unwrap().as_str() != "L";
+            instructions.push((clockwise, parse::capture(input, &cap, "number")?));
         }
 
-        Map { 
+        Ok(Map { 
             current_square: current_square, current_direction: Direction::Up, rows: rows, 
             row_count: row_count, col_count: col_count, instructions: instructions
-        }
+        })
     }
 }
 
 
 impl Problem for DayTwentyTwo {
-    fn part_one(&self, input: &str) -> String {
-        let mut map = Map::new(&input);
-        map.do_instructions();
-        let score = map.score();
-        format!("Score: {score}")
-    }
+    type Input = Map;
 
-    fn part_two(&self, _input: &str) -> String {
-        format!("Not yet implemented")
+    fn parse(&self, input: &str) -> Result<Map, ProblemError> {
+        Ok(Map::new(input)?)
     }
-}
 
+    fn part_one(&self, map: &Map) -> Result<Answer, ProblemError> {
+        let mut map = map.clone();
+        map.do_instructions();
+        let score = map.score();
+        Ok(Answer::integer("Score", score))
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const INPUT: &str = "        ...#
-        .#..
-        #...
-        ....
-...#.......#
-........#...
-..#....#....
-..........#.
-        ...#....
-        .....#..
-        .#......
-        ......#.
-
-10R5L5R10L4R5L5";
-
-    #[test]
-    fn test_map_p1() {
-        let output = DayTwentyTwo{}.part_one(&INPUT);
-        assert_eq!(output, "Score: 6032")
+    fn part_two(&self, _map: &Map) -> Result<Answer, ProblemError> {
+        Err(ProblemError::Unimplemented)
     }
 }
@@ -1,9 +1,20 @@
-use crate::Problem;
+use crate::parse::ParseError;
+use crate::problem::{Answer, Problem, ProblemError, Registration};
 use std::collections::{HashSet, HashMap};
 
 pub struct DayTwentyThree;
 
-struct Map {
+pub const REGISTRATION: Registration = Registration {
+    year: 2022,
+    day: 23,
+    title: "Unstable Diffusion",
+    parts: &[1, 2],
+    params: &[],
+    new: |_, _| Box::new(DayTwentyThree{}),
+};
+
+#[derive(Clone)]
+pub struct Map {
     elves: Vec<(i64, i64)>,
     preference: usize
 }
@@ -90,64 +101,44 @@ impl Map {
         }
     }
 
-    fn new(input: &str) -> Map {
+    fn new(input: &str) -> Result<Map, ParseError> {
         let mut elves = Vec::new();
 
         for (i, line) in input.lines().enumerate() {
-            for (j, character) in line.chars().enumerate() {
-                if character == '#' {
-                    elves.push((j as i64, i as i64));
+            for (j, (offset, character)) in line.char_indices().enumerate() {
+                match character {
+                    '#' => elves.push((j as i64, i as i64)),
+                    '.' => {},
+                    _ => return Err(ParseError::new(input, &line[offset..offset + character.len_utf8()], "expected . or #")),
                 }
             } 
         }
+        if elves.is_empty() {
+            return Err(ParseError::new(input, input, "expected at least one elf"));
+        }
 
-        Map { elves, preference: 0 }
+        Ok(Map { elves, preference: 0 })
     }
 }
 
 
 impl Problem for DayTwentyThree {
-    fn part_one(&self, input: &str) -> String {
-        let mut map = Map::new(&input);
-        map.move_elves_multi(10);
-        let free_squares = map.free_squares();
-        format!("Free squares: {free_squares}")
-    }
+    type Input = Map;
 
-    fn part_two(&self, input: &str) -> String {
-        let mut map = Map::new(&input);
-        let moves = map.move_elves_until_stationary();
-        format!("Moves until stationary: {moves}")
+    fn parse(&self, input: &str) -> Result<Map, ProblemError> {
+        Ok(Map::new(input)?)
     }
-}
-
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const INPUT: &str = "..............
-..............
-.......#......
-.....###.#....
-...#...#.#....
-....#...##....
-...#.###......
-...##.#.##....
-....#..#......
-..............
-..............
-..............";
-
-    #[test]
-    fn test_map_p1() {
-        let output = DayTwentyThree{}.part_one(&INPUT);
-        assert_eq!(output, "Free squares: 110")
+    fn part_one(&self, map: &Map) -> Result<Answer, ProblemError> {
+        let mut map = map.clone();
+        map.move_elves_multi(10);
+        let free_squares = map.free_squares();
+        Ok(Answer::integer("Free squares", free_squares))
     }
 
-    #[test]
-    fn test_map_p2() {
-        let output = DayTwentyThree{}.part_two(&INPUT);
-        assert_eq!(output, "Moves until stationary: 20")
+    fn part_two(&self, map: &Map) -> Result<Answer, ProblemError> {
+        let mut map = map.clone();
+        let moves = map.move_elves_until_stationary();
+        Ok(Answer::integer("Moves until stationary", moves))
     }
 }
@@ -1,9 +1,19 @@
-use crate::Problem;
+use crate::parse::ParseError;
+use crate::problem::{Answer, Problem, ProblemError, Registration};
 use std::collections::{VecDeque, HashSet};
 
 pub struct DayTwentyFour;
 
-#[derive(Debug)]
+pub const REGISTRATION: Registration = Registration {
+    year: 2022,
+    day: 24,
+    title: "Blizzard Basin",
+    parts: &[1, 2],
+    params: &[],
+    new: |_, _| Box::new(DayTwentyFour{}),
+};
+
+#[derive(Clone, Debug)]
 enum Direction {
     Up, 
     Down,
@@ -11,7 +21,8 @@ enum Direction {
     Right
 }
 
-struct Map {
+#[derive(Clone)]
+pub struct Map {
     blizzards: Vec<Vec<Option<Direction>>>,
     x_extent: u32,
     y_extent: u32,
@@ -107,66 +118,62 @@ impl Map {
         }
     }
 
-    fn new(input: &str) -> Map {
+    fn new(input: &str) -> Result<Map, ParseError> {
         let mut map = Vec::new();
 
-        let y_extent = input.lines().collect::<Vec<&str>>().len() as u32;
-        let x_extent = input.lines().collect::<Vec<&str>>()[0].len() as u32;
-        for line in input.lines() {
-            let row = line.chars().map(|character| 
-                match character {
-                '^' => Some(Direction::Up),
-                '>' => Some(Direction::Right),
-                '<' => Some(Direction::Left),
-                'v' => Some(Direction::Down),
-                _ => None
-            }).collect();
+        let lines: Vec<&str> = input.lines().collect();
+        let y_extent = lines.len() as u32;
+        let x_extent = lines.first().map_or(0, |line| line.len()) as u32;
+        // The valley needs walls all round and room inside for blizzards
+        if y_extent < 3 || x_extent < 3 {
+            return Err(ParseError::new(input, input, "expected a walled valley at least 3 by 3"));
+        }
+        for line in lines {
+            if line.len() as u32 != x_extent {
+                return Err(ParseError::new(input, line, &format!("expected a row {x_extent} wide")));
+            }
+            let mut row = Vec::new();
+            for (i, character) in line.char_indices() {
+                row.push(match character {
+                    '^' => Some(Direction::Up),
+                    '>' => Some(Direction::Right),
+                    '<' => Some(Direction::Left),
+                    'v' => Some(Direction::Down),
+                    '#' | '.' => None,
+                    _ => return Err(ParseError::new(input, &line[i..i + character.len_utf8()], "expected a wall, ground or blizzard")),
+                });
+            }
             map.push(row);
         }
 
 
-        Map { blizzards: map, x_extent, y_extent, memoize: Vec::new() }
+        Ok(Map { blizzards: map, x_extent, y_extent, memoize: Vec::new() })
     }
 }
 
 
-impl Problem for DayTwentyFour {
-    fn part_one(&self, input: &str) -> String {
-        let mut map = Map::new(&input);
-        let moves = map.minimum_moves(0, false).unwrap();
-        format!("Minimum moves: {moves}")
-    }
-
-    fn part_two(&self, input: &str) -> String {
-        let mut map = Map::new(&input);
-        let leg_1 = map.minimum_moves(0, false).unwrap();
-        let leg_2 = map.minimum_moves(leg_1, true).unwrap();
-        let leg_3 = map.minimum_moves(leg_2, false).unwrap();
-        format!("Minimum moves: {leg_3}")
-    }
+fn no_route() -> ProblemError {
+    ProblemError::NoSolution(String::from("no route through the blizzards"))
 }
 
+impl Problem for DayTwentyFour {
+    type Input = Map;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const INPUT: &str = "#.######
-#>>.<^<#
-#.<..<<#
-#>v.><>#
-#<^v^^>#
-######.#";
+    fn parse(&self, input: &str) -> Result<Map, ProblemError> {
+        Ok(Map::new(input)?)
+    }
 
-    #[test]
-    fn test_map_p1() {
-        let output = DayTwentyFour{}.part_one(&INPUT);
-        assert_eq!(output, "Minimum moves: 18")
+    fn part_one(&self, map: &Map) -> Result<Answer, ProblemError> {
+        let mut map = map.clone();
+        let moves = map.minimum_moves(0, false).ok_or(no_route())?;
+        Ok(Answer::integer("Minimum moves", moves))
     }
 
-    #[test]
-    fn test_map_p2() {
-        let output = DayTwentyFour{}.part_two(&INPUT);
-        assert_eq!(output, "Minimum moves: 54")
+    fn part_two(&self, map: &Map) -> Result<Answer, ProblemError> {
+        let mut map = map.clone();
+        let leg_1 = map.minimum_moves(0, false).ok_or(no_route())?;
+        let leg_2 = map.minimum_moves(leg_1, true).ok_or(no_route())?;
+        let leg_3 = map.minimum_moves(leg_2, false).ok_or(no_route())?;
+        Ok(Answer::integer("Minimum moves", leg_3))
     }
 }
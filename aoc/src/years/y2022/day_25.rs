@@ -0,0 +1,148 @@
+use crate::parse::ParseError;
+use crate::problem::{Answer, Problem, ProblemError, Registration};
+use std::collections::HashMap;
+
+pub struct DayTwentyFive;
+
+pub const REGISTRATION: Registration = Registration {
+    year: 2022,
+    day: 25,
+    title: "Full of Hot Air",
+    parts: &[1],
+    params: &[],
+    new: |_, _| Box::new(DayTwentyFive{}),
+};
+
+pub struct SnafuDecoder {
+    snafu: HashMap<String, i64>
+}
+
+impl SnafuDecoder {
+    fn sum(&self) -> String {
+        let sum = self.snafu.values().sum();
+
+        SnafuDecoder::encode(sum)
+    }
+    
+    fn encode(snafu: i64) -> String {
+        let mut val = snafu.clone();
+        let mut str = String::new();
+
+        loop {
+            let digit = match (val + 2) % 5 {
+                4 => "2",
+                3 => "1",
+                2 => "0",
+                1 => "-",
+                0 => "=",
+                _ => panic!("Don't expect val"),
+            };
+            str = format!("{digit}{str}");
+            val = (val + 2) / 5;
+            if val == 0 {
+                break;
+            }
+        }
+        
+        return str
+    }
+
+    fn digit(character: char) -> Option<i64> {
+        match character {
+            '2' => Some(2),
+            '1' => Some(1),
+            '0' => Some(0),
+            '-' => Some(-1),
+            '=' => Some(-2),
+            _ => None,
+        }
+    }
+
+    fn decode(snafu: &str) -> Option<i64> {
+        let mut val: i64 = 0;
+        for (i, character) in snafu.chars().enumerate() {
+            let multiplier: i64 = (5 as i64).pow(snafu.len() as u32 - i as u32 - 1);
+            val += SnafuDecoder::digit(character)? * multiplier;
+        }
+
+        return Some(val);
+    }
+
+    fn new(input: &str) -> Result<SnafuDecoder, ParseError> {
+        let mut snafu = HashMap::new();
+        for line in input.lines() {
+            let line = line.trim();
+            if let Some((i, c)) = line.char_indices().find(|(_, c)| SnafuDecoder::digit(*c).is_none()) {
+                return Err(ParseError::new(input, &line[i..i + c.len_utf8()], "expected a SNAFU digit: 2, 1, 0, - or ="));
+            }
+            let val = SnafuDecoder::decode(line).unwrap();
+
+            snafu.insert(String::from(line), val);
+        }
+
+        Ok(SnafuDecoder { snafu })
+    }
+}
+
+
+impl Problem for DayTwentyFive {
+    type Input = SnafuDecoder;
+
+    fn parse(&self, input: &str) -> Result<SnafuDecoder, ProblemError> {
+        Ok(SnafuDecoder::new(input)?)
+    }
+
+    fn part_one(&self, snafu_decoder: &SnafuDecoder) -> Result<Answer, ProblemError> {
+        let sum = snafu_decoder.sum();
+        Ok(Answer::text("Snafu sum", &sum))
+    }
+
+    fn part_two(&self, _snafu_decoder: &SnafuDecoder) -> Result<Answer, ProblemError> {
+        Err(ProblemError::Unimplemented)
+    }
+
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snafu_decodes() {
+        assert_eq!(SnafuDecoder::decode("1"), Some(1));
+        assert_eq!(SnafuDecoder::decode("2"), Some(2));
+        assert_eq!(SnafuDecoder::decode("1="), Some(3));
+        assert_eq!(SnafuDecoder::decode("1-"), Some(4));
+        assert_eq!(SnafuDecoder::decode("10"), Some(5));
+        assert_eq!(SnafuDecoder::decode("11"), Some(6));
+        assert_eq!(SnafuDecoder::decode("12"), Some(7));
+        assert_eq!(SnafuDecoder::decode("2="), Some(8));
+        assert_eq!(SnafuDecoder::decode("2-"), Some(9));
+        assert_eq!(SnafuDecoder::decode("20"), Some(10));
+        assert_eq!(SnafuDecoder::decode("1=0"), Some(15));
+        assert_eq!(SnafuDecoder::decode("1-0"), Some(20));
+        assert_eq!(SnafuDecoder::decode("1=11-2"), Some(2022));
+        assert_eq!(SnafuDecoder::decode("1-0---0"), Some(12345));
+        assert_eq!(SnafuDecoder::decode("1121-1110-1=0"), Some(314159265));
+    }
+
+    #[test]
+    fn test_snafu_encodes() {
+        assert_eq!(SnafuDecoder::encode(1), "1");
+        assert_eq!(SnafuDecoder::encode(2), "2");
+        assert_eq!(SnafuDecoder::encode(3), "1=");
+        assert_eq!(SnafuDecoder::encode(4), "1-");
+        assert_eq!(SnafuDecoder::encode(5), "10");
+        assert_eq!(SnafuDecoder::encode(6), "11");
+        assert_eq!(SnafuDecoder::encode(7), "12");
+        assert_eq!(SnafuDecoder::encode(8), "2=");
+        assert_eq!(SnafuDecoder::encode(9), "2-");
+        assert_eq!(SnafuDecoder::encode(10), "20");
+        assert_eq!(SnafuDecoder::encode(15), "1=0");
+        assert_eq!(SnafuDecoder::encode(20), "1-0");
+        assert_eq!(SnafuDecoder::encode(2022), "1=11-2");
+        assert_eq!(SnafuDecoder::encode(12345), "1-0---0");
+        assert_eq!(SnafuDecoder::encode(314159265), "1121-1110-1=0");
+    }
+}
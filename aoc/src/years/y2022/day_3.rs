@@ -1,8 +1,18 @@
-use crate::problem::Problem;
+use crate::parse::ParseError;
+use crate::problem::{Answer, Problem, ProblemError, Registration};
 use std::collections::HashSet;
 
 pub struct DayThree {}
 
+pub const REGISTRATION: Registration = Registration {
+    year: 2022,
+    day: 3,
+    title: "Rucksack Reorganization",
+    parts: &[1, 2],
+    params: &[],
+    new: |_, _| Box::new(DayThree{}),
+};
+
 fn convert_char_to_int(character: char) -> u32 {
     match character {
         'a'..='z' => character as u32 - ('a' as u32) + 1,
@@ -29,13 +39,34 @@ fn get_doubles_line(line: &str) -> Vec<char> {
 
 }
 
-fn get_doubles_input(input: &str) -> Vec<char> { 
-    let mut doubles: Vec<char> = Vec::new();
+// Rucksacks hold an even number of items, each a letter
+fn check_rucksack(input: &str, line: &str) -> Result<(), ParseError> {
+    if let Some((i, c)) = line.char_indices().find(|(_, c)| !c.is_ascii_alphabetic()) {
+        return Err(ParseError::new(input, &line[i..i + c.len_utf8()], "expected an item letter"));
+    }
+    if line.len() % 2 != 0 {
+        return Err(ParseError::new(input, line, "rucksack has an odd number of items"));
+    }
+    Ok(())
+}
 
+fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+    let mut rucksacks = Vec::new();
     for line in input.split("\n") {
         if line == "" {
             continue
         }
+        check_rucksack(input, line.trim())?;
+        rucksacks.push(String::from(line.trim()));
+    }
+
+    Ok(rucksacks)
+}
+
+fn get_doubles_input(rucksacks: &[String]) -> Vec<char> {
+    let mut doubles: Vec<char> = Vec::new();
+
+    for line in rucksacks {
         let doubles_line = get_doubles_line(line);
 
         for d in doubles_line {
@@ -46,14 +77,14 @@ fn get_doubles_input(input: &str) -> Vec<char> {
     return doubles
 }
 
-fn get_priorities_input(input: &str) -> Vec<u32> {
-    get_doubles_input(input).iter().map(|x| convert_char_to_int(*x)).collect()
+fn get_priorities_input(rucksacks: &[String]) -> Vec<u32> {
+    get_doubles_input(rucksacks).iter().map(|x| convert_char_to_int(*x)).collect()
 }
 
-fn get_badges_group(group: Vec<&str>) -> char {
+fn get_badges_group(group: &[String]) -> Result<char, ProblemError> {
     let group_size = group.len();
     if group_size != 3 {
-        panic!("Got group of len {group_size}")
+        return Err(ProblemError::NoSolution(format!("expected a group of 3 rucksacks, got {group_size}")))
     };
 
     let set1: HashSet<char> = group[0].chars().collect();
@@ -64,48 +95,57 @@ fn get_badges_group(group: Vec<&str>) -> char {
 
     let badge_count = badges.len();
     if badge_count != 1 {
-        panic!("found {badge_count} badges")
+        return Err(ProblemError::NoSolution(format!("found {badge_count} badges in group at {:?}", group[0])))
     };
 
-    badges[0]
+    Ok(badges[0])
 }
 
-fn get_badges_input(input: &str) -> Vec<char> {
+fn get_badges_input(rucksacks: &[String]) -> Result<Vec<char>, ProblemError> {
     let mut badges: Vec<char> = Vec::new();
 
-    for group in input.split("\n").collect::<Vec<&str>>().chunks(3) {
-        let badge = get_badges_group(group.iter().map(|x| x.trim()).collect());
+    for group in rucksacks.chunks(3) {
+        let badge = get_badges_group(group)?;
 
         badges.push(badge)
     }
     
-    badges
+    Ok(badges)
 }
 
 
-fn get_badge_priorities_input(input: &str) -> Vec<u32> {
-    get_badges_input(input).iter().map(|x| convert_char_to_int(*x)).collect()
+fn get_badge_priorities_input(rucksacks: &[String]) -> Result<Vec<u32>, ProblemError> {
+    Ok(get_badges_input(rucksacks)?.iter().map(|x| convert_char_to_int(*x)).collect())
 }
 
 
 impl Problem for DayThree {
-    fn part_one(&self, input: &str) -> String {
-        let priorities = get_priorities_input(input);
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Vec<String>, ProblemError> {
+        Ok(parse(input)?)
+    }
+
+    fn part_one(&self, rucksacks: &Vec<String>) -> Result<Answer, ProblemError> {
+        let priorities = get_priorities_input(rucksacks);
         let total: u32 = priorities.iter().sum();
-        format!("Total score: {}", total)
+        Ok(Answer::integer("Total score", total))
     }
 
-    fn part_two(&self, input: &str) -> String {
-        let badges = get_badge_priorities_input(input);
+    fn part_two(&self, rucksacks: &Vec<String>) -> Result<Answer, ProblemError> {
+        let badges = get_badge_priorities_input(rucksacks)?;
         let total: u32 = badges.iter().sum();
-        format!("Total score: {}", total)
+        Ok(Answer::integer("Total score", total))
     }
 }
 
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    const EXAMPLE: &str = include_str!("../../../examples/2022/day_3/example.txt");
+
     #[test]
     fn test_convert_char_to_int () {
         assert_eq!(convert_char_to_int('a'), 1);
@@ -128,13 +168,7 @@ mod tests {
 
     #[test]
     fn test_get_doubles_input() {
-        let input = "vJrwpWtwJgWrhcsFMMfFFhFp
-        jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
-        PmmdzqPrVvPwwTWBwg
-        wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
-        ttgJtRGJQctTZtZT
-        CrZsJsPPZsGzwwsLwLmpwMDw";
-        let result: Vec<char> = get_doubles_input(&input);
+        let result: Vec<char> = get_doubles_input(&parse(EXAMPLE).unwrap());
         //assert_eq!(result.len(), 6);
         assert_eq!(result[0], 'p');
         assert_eq!(result[1], 'L');
@@ -146,13 +180,7 @@ mod tests {
 
     #[test]
     fn test_get_priorities_input() {
-        let input = "vJrwpWtwJgWrhcsFMMfFFhFp
-        jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
-        PmmdzqPrVvPwwTWBwg
-        wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
-        ttgJtRGJQctTZtZT
-        CrZsJsPPZsGzwwsLwLmpwMDw";
-        let result: Vec<u32> = get_priorities_input(&input);
+        let result: Vec<u32> = get_priorities_input(&parse(EXAMPLE).unwrap());
         assert_eq!(result.len(), 6);
         assert_eq!(result[0], 16);
         assert_eq!(result[1], 38);
@@ -164,13 +192,7 @@ mod tests {
 
     #[test]
     fn test_get_badges_input() {
-        let input = "vJrwpWtwJgWrhcsFMMfFFhFp
-        jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
-        PmmdzqPrVvPwwTWBwg
-        wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
-        ttgJtRGJQctTZtZT
-        CrZsJsPPZsGzwwsLwLmpwMDw";
-        let result: Vec<char> = get_badges_input(&input);
+        let result: Vec<char> = get_badges_input(&parse(EXAMPLE).unwrap()).unwrap();
         assert_eq!(result.len(), 2);
         assert_eq!(result[0], 'r');
         assert_eq!(result[1], 'Z');
@@ -178,39 +200,9 @@ mod tests {
 
     #[test]
     fn test_get_badge_priorities_input() {
-        let input = "vJrwpWtwJgWrhcsFMMfFFhFp
-        jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
-        PmmdzqPrVvPwwTWBwg
-        wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
-        ttgJtRGJQctTZtZT
-        CrZsJsPPZsGzwwsLwLmpwMDw";
-        let result: Vec<u32> = get_badge_priorities_input(&input);
+        let result: Vec<u32> = get_badge_priorities_input(&parse(EXAMPLE).unwrap()).unwrap();
         assert_eq!(result.len(), 2);
         assert_eq!(result[0], 18);
         assert_eq!(result[1], 52);
     }
-
-    #[test]
-    fn test_find_priorities_p1() {
-        let input = "vJrwpWtwJgWrhcsFMMfFFhFp
-        jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
-        PmmdzqPrVvPwwTWBwg
-        wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
-        ttgJtRGJQctTZtZT
-        CrZsJsPPZsGzwwsLwLmpwMDw";
-        let result = DayThree{}.part_one(&input);
-        assert_eq!(result, "Total score: 157");
-    }
-
-    #[test]
-    fn test_find_badges_p2() {
-        let input = "vJrwpWtwJgWrhcsFMMfFFhFp
-        jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
-        PmmdzqPrVvPwwTWBwg
-        wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
-        ttgJtRGJQctTZtZT
-        CrZsJsPPZsGzwwsLwLmpwMDw";
-        let result = DayThree{}.part_two(&input);
-        assert_eq!(result, "Total score: 70");
-    }
 }
\ No newline at end of file
@@ -1,7 +1,17 @@
-use crate::problem::Problem;
+use crate::parse::{self, ParseError};
+use crate::problem::{Answer, Problem, ProblemError, Registration};
 
 pub struct DayFour {}
 
+pub const REGISTRATION: Registration = Registration {
+    year: 2022,
+    day: 4,
+    title: "Camp Cleanup",
+    parts: &[1, 2],
+    params: &[],
+    new: |_, _| Box::new(DayFour{}),
+};
+
 
 fn check_for_overlap_strict(start_a: i32, end_a: i32, start_b: i32, end_b: i32) -> i32 {
     if start_a <= start_b && end_a >= end_b {
@@ -27,25 +37,28 @@ fn check_for_overlap_lenient(start_a: i32, end_a: i32, start_b: i32, end_b: i32)
     }
 }
 
-fn parse_elf(elf: &str) -> (i32, i32) {
-    let min_max: Vec<&str> = elf.split("-").collect();
-    if min_max.len() != 2 {
-        panic!{"Found line without 2 elves"}
-    }
+fn parse_elf(input: &str, elf: &str) -> Result<(i32, i32), ParseError> {
+    let (min, max) = parse::split_once(input, elf, "-")?;
 
-    (min_max[0].parse().unwrap(), min_max[1].parse().unwrap())
+    Ok((parse::number(input, min)?, parse::number(input, max)?))
 }
 
-fn get_overlap_count<F: Fn(i32, i32, i32, i32) -> i32>(input: &str, overlap_check: F) -> i32 {
-    let mut overlap_count = 0;
+// The section ranges, from start to end, of a pair of elves
+type ElfPair = ((i32, i32), (i32, i32));
+
+fn parse(input: &str) -> Result<Vec<ElfPair>, ParseError> {
+    let mut elf_pairs = Vec::new();
     for elf_pair in input.split("\n") {
-        let elves: Vec<&str> = elf_pair.trim().split(",").collect();
-        if elves.len() != 2 {
-            panic!{"Found line without 2 elves"}
-        }
-        let (elf_a_start, elf_a_end) = parse_elf(elves[0]);
-        let (elf_b_start, elf_b_end) = parse_elf(elves[1]);
+        let (elf_a, elf_b) = parse::split_once(input, elf_pair.trim(), ",")?;
+        elf_pairs.push((parse_elf(input, elf_a)?, parse_elf(input, elf_b)?));
+    }
 
+    Ok(elf_pairs)
+}
+
+fn get_overlap_count<F: Fn(i32, i32, i32, i32) -> i32>(elf_pairs: &[ElfPair], overlap_check: F) -> i32 {
+    let mut overlap_count = 0;
+    for ((elf_a_start, elf_a_end), (elf_b_start, elf_b_end)) in elf_pairs.iter().copied() {
         overlap_count += overlap_check(elf_a_start, elf_a_end, elf_b_start, elf_b_end);
     }
     
@@ -54,17 +67,24 @@ fn get_overlap_count<F: Fn(i32, i32, i32, i32) -> i32>(input: &str, overlap_chec
 
 
 impl Problem for DayFour {
-    fn part_one(&self, input: &str) -> String {
-        let overlap_count = get_overlap_count(input, check_for_overlap_strict);
-        format!("Overlap count: {}", overlap_count)
+    type Input = Vec<ElfPair>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ProblemError> {
+        Ok(parse(input)?)
     }
 
-    fn part_two(&self, input: &str) -> String {
-        let overlap_count = get_overlap_count(input, check_for_overlap_lenient);
-        format!("Overlap count: {}", overlap_count)
+    fn part_one(&self, elf_pairs: &Self::Input) -> Result<Answer, ProblemError> {
+        let overlap_count = get_overlap_count(elf_pairs, check_for_overlap_strict);
+        Ok(Answer::integer("Overlap count", overlap_count))
+    }
+
+    fn part_two(&self, elf_pairs: &Self::Input) -> Result<Answer, ProblemError> {
+        let overlap_count = get_overlap_count(elf_pairs, check_for_overlap_lenient);
+        Ok(Answer::integer("Overlap count", overlap_count))
     }
 }
 
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -94,32 +114,9 @@ mod tests {
 
     #[test]
     fn test_parse_elf() {
-        let (elf_a_start, elf_a_end) = parse_elf("1-2");
+        let (elf_a_start, elf_a_end) = parse_elf("1-2", "1-2").unwrap();
         assert_eq!(elf_a_start, 1);
         assert_eq!(elf_a_end, 2);
-    }
-
-    #[test]
-    fn test_find_overlap_count_p1() {
-        let input = "2-4,6-8
-        2-3,4-5
-        5-7,7-9
-        2-8,3-7
-        6-6,4-6
-        2-6,4-8";
-        let result = DayFour{}.part_one(&input);
-        assert_eq!(result, "Overlap count: 2");
-    }
-
-    #[test]
-    fn test_find_overlap_count_p2() {
-        let input = "2-4,6-8
-        2-3,4-5
-        5-7,7-9
-        2-8,3-7
-        6-6,4-6
-        2-6,4-8";
-        let result = DayFour{}.part_two(&input);
-        assert_eq!(result, "Overlap count: 4");
+        assert_eq!(parse_elf("1-x", "1-x").unwrap_err().column, 3);
     }
 }
\ No newline at end of file
@@ -0,0 +1,257 @@
+use crate::parse::{self, ParseError};
+use crate::problem::{Answer, Problem, ProblemError, Registration};
+use regex::Regex;
+
+pub struct DayFive {}
+
+pub const REGISTRATION: Registration = Registration {
+    year: 2022,
+    day: 5,
+    title: "Supply Stacks",
+    parts: &[1, 2],
+    params: &[],
+    new: |_, _| Box::new(DayFive{}),
+};
+
+#[derive(Clone, Copy)]
+enum Ordering {
+    Lifo, 
+    Fifo
+}
+
+#[derive(Clone, Copy)]
+struct Instruction {
+    count: usize, 
+    from: usize,
+    to: usize,
+}
+
+impl Instruction {
+    pub fn new(input: &str, instruction: &str) -> Result<Instruction, ParseError> {
+        lazy_static! {
+            static ref RE: Regex = Regex::new(r"^\s*move\s(?P<count>[0-9]+)\sfrom\s(?P<from>[1-9][0-9]*)\sto\s(?P<to>[1-9][0-9]*)\s*$").unwrap();
+        }
+    
+        let cap = parse::captures(input, &RE, instruction.trim(), "a move instruction")?;
+        let count: usize = parse::capture(input, &cap, "count")?;
+        let from: usize = parse::capture::<usize>(input, &cap, "from")? - 1;
+        let to: usize = parse::capture::<usize>(input, &cap, "to")? - 1;
+    
+        Ok(Instruction { count, from, to })
+    }
+}
+
+#[derive(Clone)]
+struct Crates {
+    crates: Vec<Box<Vec<char>>>,
+    ordering: Ordering,
+}
+
+impl Crates {
+    pub fn new(input: &str, ordering: Ordering) -> Crates {
+        // rsplit reverses to traverse up the stack 
+        let lines: Vec<&str> = input.rsplit("\n").collect();
+
+        // first line example: " 1   2   3 "
+        let count = lines[0].trim().split("   ").collect::<Vec<&str>>().len();
+        let mut crates = Vec::new();
+        for _ in 0..count {
+            crates.push(Box::new(Vec::new()))
+        }
+
+        for i in 1..lines.len() {
+            let line: Vec<char> = lines[i].chars().collect();
+            // line example: "[Z] [M] [P]"
+            // Note any combination of indices could be absent, and trailing
+            // empty positions may be missing altogether
+            for (j, stack) in crates.iter_mut().enumerate() {
+                match line.get(1 + 4 * j) {
+                    Some(' ') | None => {},
+                    Some(character) => stack.push(*character),
+                }
+            }
+        }
+        
+        Crates { crates, ordering }
+    }
+
+    fn pop(&mut self, crate_index: usize) -> Result<char, ProblemError> {
+        self.crates[crate_index].pop()
+            .ok_or_else(|| ProblemError::NoSolution(format!("stack {} is empty", crate_index + 1)))
+    }
+    
+    fn push(&mut self, crate_index: usize, element: char) {
+        self.crates[crate_index].push(element)
+    }
+
+    fn do_move_lifo(&mut self, instruction: Instruction) -> Result<(), ProblemError> {
+        for _ in 0..instruction.count {
+            let move_element = self.pop(instruction.from)?;
+            self.push(instruction.to, move_element);
+        }
+        Ok(())
+    }
+
+    fn do_move_fifo(&mut self, instruction: Instruction) -> Result<(), ProblemError> {
+        let mut tmp_vec = Vec::new();
+        for _ in 0..instruction.count {
+            tmp_vec.push(self.pop(instruction.from)?);
+        }
+
+        for _ in 0..instruction.count {
+            self.push(instruction.to, tmp_vec.pop().unwrap());
+        }
+        Ok(())
+    }
+    
+    // This would be better being polymorphic but Rust doesn't have inheritance.. 
+    pub fn do_move(&mut self, instruction: Instruction) -> Result<(), ProblemError> {
+        match self.ordering {
+            Ordering::Lifo => self.do_move_lifo(instruction),
+            Ordering::Fifo => self.do_move_fifo(instruction)
+        }
+    }
+
+    pub fn get_final_str(&self) -> String {
+        let mut result = String::new();
+        for c in &self.crates {
+            if let Some(top) = c.last() {
+                result.push(*top);
+            }
+        }
+        result
+    }
+}
+
+// The starting stacks and the moves to make on them
+pub struct Procedure {
+    crates: Crates,
+    instructions: Vec<Instruction>,
+}
+
+fn parse(input: &str) -> Result<Procedure, ParseError> {
+    let (crates_str, instructions_str) = parse::split_once(input, input, "\n\n")?;
+    let crates = Crates::new(crates_str, Ordering::Lifo);
+
+    let mut instructions = Vec::new();
+    for instruction_str in instructions_str.split("\n") {
+        let instruction = Instruction::new(input, instruction_str)?;
+        if instruction.from >= crates.crates.len() || instruction.to >= crates.crates.len() {
+            return Err(ParseError::new(input, instruction_str.trim(), "move refers to a missing stack"));
+        }
+        instructions.push(instruction);
+    }
+
+    Ok(Procedure { crates, instructions })
+}
+
+fn get_final_crates(procedure: &Procedure, ordering: Ordering) -> Result<String, ProblemError> {
+    let mut crates = Crates { ordering, ..procedure.crates.clone() };
+
+    for instruction in &procedure.instructions {
+        crates.do_move(*instruction)?
+    }
+    
+    Ok(crates.get_final_str())
+}
+
+
+impl Problem for DayFive {
+    type Input = Procedure;
+
+    fn parse(&self, input: &str) -> Result<Procedure, ProblemError> {
+        Ok(parse(input)?)
+    }
+
+    fn part_one(&self, procedure: &Procedure) -> Result<Answer, ProblemError> {
+        let final_crates = get_final_crates(procedure, Ordering::Lifo)?;
+        Ok(Answer::text("Top of the crates at finish", &final_crates))
+    }
+
+    fn part_two(&self, procedure: &Procedure) -> Result<Answer, ProblemError> {
+        let final_crates = get_final_crates(procedure, Ordering::Fifo)?;
+        Ok(Answer::text("Top of the crates at finish", &final_crates))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_initial_crates() {
+        let input = "    [D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3";
+        let crates = Crates::new(&input, Ordering::Lifo);
+        assert_eq!(crates.crates.len(), 3);
+        assert_eq!(crates.crates[0].len(), 2);
+        assert_eq!(crates.crates[0][0], 'Z');
+        assert_eq!(crates.crates[0][1], 'N');
+        assert_eq!(crates.crates[1].len(), 3);
+        assert_eq!(crates.crates[1][0], 'M');
+        assert_eq!(crates.crates[1][1], 'C');
+        assert_eq!(crates.crates[1][2], 'D');
+        assert_eq!(crates.crates[2].len(), 1);
+        assert_eq!(crates.crates[2][0], 'P');
+    }
+
+    #[test]
+    fn test_parse_instruction() {
+        let instruction = "    move 1 from 2 to 3";
+        let instruction = Instruction::new(&instruction, &instruction).unwrap();
+        assert_eq!(instruction.count, 1);
+        assert_eq!(instruction.from, 1);
+        assert_eq!(instruction.to, 2);
+    }
+
+    #[test]
+    fn test_do_move_lifo() {
+        let mut crates = Crates::new("[C]    
+[B]    
+[A] [D]
+ 1   2 ",
+        Ordering::Lifo);
+        
+        crates.do_move(Instruction::new("move 2 from 1 to 2", "move 2 from 1 to 2").unwrap()).unwrap();
+        assert_eq!(crates.crates.len(), 2);
+        assert_eq!(crates.crates[0].len(), 1);
+        assert_eq!(crates.crates[0][0], 'A');
+        assert_eq!(crates.crates[1].len(), 3);
+        assert_eq!(crates.crates[1][0], 'D');
+        assert_eq!(crates.crates[1][1], 'C');
+        assert_eq!(crates.crates[1][2], 'B');
+    }
+
+    #[test]
+    fn test_do_move_fifo() {
+        let mut crates = Crates::new("[C]    
+[B]    
+[A] [D]
+ 1   2 ",
+        Ordering::Fifo);
+        
+        crates.do_move(Instruction::new("move 2 from 1 to 2", "move 2 from 1 to 2").unwrap()).unwrap();
+        assert_eq!(crates.crates.len(), 2);
+        assert_eq!(crates.crates[0].len(), 1);
+        assert_eq!(crates.crates[0][0], 'A');
+        assert_eq!(crates.crates[1].len(), 3);
+        assert_eq!(crates.crates[1][0], 'D');
+        assert_eq!(crates.crates[1][1], 'B');
+        assert_eq!(crates.crates[1][2], 'C');
+    }
+
+    #[test]
+    fn test_malformed_instruction() {
+        let input = "[A]\n 1 \n\nmove 1 from 1 to 1\nmove one from 1 to 1";
+        let result = DayFive{}.parse(input).and_then(|input| DayFive{}.part_one(&input));
+        let error = match result {
+            Err(ProblemError::Parse(error)) => error,
+            result => panic!("Expected a parse error, got {result:?}"),
+        };
+        assert_eq!((error.line, error.column), (5, 1));
+        assert_eq!(error.text, "move one from 1 to 1");
+        assert_eq!(error.message, "expected a move instruction");
+    }
+}
\ No newline at end of file
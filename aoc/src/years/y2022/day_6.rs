@@ -0,0 +1,62 @@
+use crate::problem::{Answer, Problem, ProblemError, Registration};
+use std::collections::HashSet;
+
+pub struct DaySix {}
+
+pub const REGISTRATION: Registration = Registration {
+    year: 2022,
+    day: 6,
+    title: "Tuning Trouble",
+    parts: &[1, 2],
+    params: &[],
+    new: |_, _| Box::new(DaySix{}),
+};
+
+
+struct SignalProcessor {
+    marker_position: Option<usize>,
+    marker_size: usize
+}
+
+impl SignalProcessor {
+    pub fn new(packet: String, marker_size: usize) -> SignalProcessor {
+        let mut i = 0;
+        let mut marker_position = None;
+        while i + marker_size < packet.len() {
+            let string_slice: HashSet<char> = packet[i..i + marker_size].chars().collect();
+            if string_slice.len() == marker_size {
+                marker_position = Some(i);
+                break;
+            }
+            i += 1
+        }
+        
+        SignalProcessor { marker_position, marker_size }
+    }
+
+    pub fn get_marker(&self) -> Option<usize> {
+        Some(self.marker_position? + self.marker_size)
+    }
+}
+
+impl Problem for DaySix {
+    type Input = String;
+
+    fn parse(&self, input: &str) -> Result<String, ProblemError> {
+        Ok(String::from(input))
+    }
+
+    fn part_one(&self, packet: &String) -> Result<Answer, ProblemError> {
+        let signal_processor = SignalProcessor::new(packet.clone(), 4);
+        let packet_start = signal_processor.get_marker()
+            .ok_or(ProblemError::NoSolution(String::from("no start of packet marker")))?;
+        Ok(Answer::integer("Packet start", packet_start))
+    }
+
+    fn part_two(&self, packet: &String) -> Result<Answer, ProblemError> {
+        let signal_processor = SignalProcessor::new(packet.clone(), 14);
+        let message_start = signal_processor.get_marker()
+            .ok_or(ProblemError::NoSolution(String::from("no start of message marker")))?;
+        Ok(Answer::integer("Start of message", message_start))
+    }
+}
@@ -1,10 +1,20 @@
-use crate::problem::Problem;
+use crate::parse::{self, ParseError};
+use crate::problem::{Answer, Problem, ProblemError, Registration};
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use regex::Regex;
 
 pub struct DaySeven {}
 
+pub const REGISTRATION: Registration = Registration {
+    year: 2022,
+    day: 7,
+    title: "No Space Left On Device",
+    parts: &[1, 2],
+    params: &[],
+    new: |_, _| Box::new(DaySeven{}),
+};
+
 #[allow(dead_code)]
 struct File {
     name: String,
@@ -49,29 +59,32 @@ fn get_sizes(dirs: &HashMap<PathBuf, Directory>) -> HashMap<PathBuf, u32> {
     sizes
 }
         
-fn change_dir(dirs: &mut HashMap<PathBuf, Directory>, current_path: &PathBuf, relative_path: &str) -> PathBuf {
+fn change_dir(input: &str, dirs: &mut HashMap<PathBuf, Directory>, current_path: &PathBuf, relative_path: &str) -> Result<PathBuf, ParseError> {
     let path: PathBuf = match relative_path {
         "/" => Path::new("/").to_path_buf(),
-        ".." => current_path.parent().unwrap().to_path_buf(),
+        ".." => match current_path.parent() {
+            Some(parent) => parent.to_path_buf(),
+            None => return Err(ParseError::new(input, relative_path, "can't leave the root directory")),
+        },
         _ => current_path.join(relative_path),
     };
-    path.to_str().unwrap();
 
     dirs.entry(path.clone()).or_default();
-    path
+    Ok(path)
 }
 
-fn add_file(dirs: &mut HashMap<PathBuf, Directory>, dir_path: &PathBuf, file: &str)  {
+fn add_file(input: &str, dirs: &mut HashMap<PathBuf, Directory>, dir_path: &PathBuf, file: &str) -> Result<(), ParseError> {
     let dir: &mut Directory = dirs.get_mut(dir_path).unwrap();
     lazy_static! {
         static ref RE: Regex = Regex::new(r"^(?P<size>[0-9]+)\s(?P<name>[a-z.]+)\s*$").unwrap();
     }
     
-    let cap = RE.captures(file.trim()).unwrap();
-    let size: u32 = cap.name("size").unwrap().as_str().parse::<u32>().unwrap();
-    let name: String = String::from(cap.name("name").unwrap().as_str());
+    let cap = parse::captures(input, &RE, file.trim(), "a file size and name")?;
+    let size: u32 = parse::capture(input, &cap, "size")?;
+    let name: String = parse::capture(input, &cap, "name")?;
         
-    dir.files.push(File { name: name, size: size })
+    dir.files.push(File { name: name, size: size });
+    Ok(())
 }
 
 fn add_subdir(dirs: &mut HashMap<PathBuf, Directory>, dir_path: &PathBuf, subdir: &str) {
@@ -81,40 +94,45 @@ fn add_subdir(dirs: &mut HashMap<PathBuf, Directory>, dir_path: &PathBuf, subdir
     dirs.entry(subdir_filepath).or_default();
 }
 
-fn parse(input: &str) -> HashMap<PathBuf, u32> {
+fn parse(input: &str) -> Result<HashMap<PathBuf, u32>, ParseError> {
     let mut dirs: HashMap<PathBuf, Directory> = HashMap::new();
     let mut current_filepath = Path::new("/").to_path_buf();
+    dirs.entry(current_filepath.clone()).or_default();
     for output in input.lines() {
         if output.starts_with("$ cd") {
-            current_filepath = change_dir(&mut dirs, &current_filepath, output[4..].trim());
+            current_filepath = change_dir(input, &mut dirs, &current_filepath, output[4..].trim())?;
         } else if output.starts_with("$ ls") {
             continue
         } else {
             if output.starts_with("dir") {
                 add_subdir(&mut dirs, &current_filepath, &output[4..]);
             } else {
-                add_file(&mut dirs, &current_filepath, output);
+                add_file(input, &mut dirs, &current_filepath, output)?;
             }
         }
     }
     
-    get_sizes(&dirs)
+    Ok(get_sizes(&dirs))
 }
 
 impl Problem for DaySeven {
-    fn part_one(&self, input: &str) -> String {
-        let sizes = parse(input);
+    type Input = HashMap<PathBuf, u32>;
+
+    fn parse(&self, input: &str) -> Result<HashMap<PathBuf, u32>, ProblemError> {
+        Ok(parse(input)?)
+    }
+
+    fn part_one(&self, sizes: &HashMap<PathBuf, u32>) -> Result<Answer, ProblemError> {
         let mut total = 0;
         for size in sizes.values() {
             if *size < 100000 {
                 total += size;
             }
         }
-        format!("Sum of sizes below 100000: {total}")
+        Ok(Answer::integer("Sum of sizes below 100000", total))
     }
 
-    fn part_two(&self, input: &str) -> String {
-        let sizes = parse(input);
+    fn part_two(&self, sizes: &HashMap<PathBuf, u32>) -> Result<Answer, ProblemError> {
         let total: u32 = *sizes.get(&Path::new("/").to_path_buf()).unwrap();
         let space_to_free = total - 40000000;
         let mut size_vec: Vec<&u32> = sizes.values().collect();
@@ -122,71 +140,9 @@ impl Problem for DaySeven {
     
         for value in size_vec {
             if *value > space_to_free {
-                return format!("Size to be deleted: {value}");
+                return Ok(Answer::integer("Size to be deleted", *value));
             }
         }
-        panic!("Didn't find any directories large enough")
-    }
-}
-
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_filesystem_p1() {
-        let input="$ cd /
-$ ls
-dir a
-14848514 b.txt
-8504156 c.dat
-dir d
-$ cd a
-$ ls
-dir e
-29116 f
-2557 g
-62596 h.lst
-$ cd e
-$ ls
-584 i
-$ cd ..
-$ cd ..
-$ cd d
-$ ls
-4060174 j
-8033020 d.log
-5626152 d.ext
-7214296 k";
-        assert_eq!(DaySeven{}.part_one(&input), "Sum of sizes below 100000: 95437");
-    }
-
-    #[test]
-    fn test_filesystem_p2() {
-        let input="$ cd /
-$ ls
-dir a
-14848514 b.txt
-8504156 c.dat
-dir d
-$ cd a
-$ ls
-dir e
-29116 f
-2557 g
-62596 h.lst
-$ cd e
-$ ls
-584 i
-$ cd ..
-$ cd ..
-$ cd d
-$ ls
-4060174 j
-8033020 d.log
-5626152 d.ext
-7214296 k";
-        assert_eq!(DaySeven{}.part_two(&input), "Size to be deleted: 24933642");
+        Err(ProblemError::NoSolution(String::from("didn't find any directories large enough")))
     }
 }
@@ -1,10 +1,20 @@
-use crate::problem::Problem;
+use crate::parse::ParseError;
+use crate::problem::{Answer, Problem, ProblemError, Registration};
 use std::collections::HashMap;
 
 pub struct DayEight {}
 
+pub const REGISTRATION: Registration = Registration {
+    year: 2022,
+    day: 8,
+    title: "Treetop Tree House",
+    parts: &[1, 2],
+    params: &[],
+    new: |_, _| Box::new(DayEight{}),
+};
 
-struct Forest {
+
+pub struct Forest {
     trees: HashMap<(usize, usize), u32>,
     row_size: usize, 
     col_size: usize
@@ -99,11 +109,24 @@ impl Forest {
         self.trees.keys().map(|k| self.scenic_score(k.0, k.1)).max().unwrap() 
     }
 
-    pub fn new(input: &str) -> Forest {
+    pub fn new(input: &str) -> Result<Forest, ParseError> {
         let mut trees: HashMap<(usize, usize), u32> = HashMap::new();
         let mut grid: Vec<Vec<u32>> = Vec::new();
         for line in input.lines() {
-            grid.push(line.chars().map(|x| x.to_digit(10).unwrap()).collect());
+            let mut row = Vec::new();
+            for (i, x) in line.char_indices() {
+                match x.to_digit(10) {
+                    Some(height) => row.push(height),
+                    None => return Err(ParseError::new(input, &line[i..i + x.len_utf8()], "expected a tree height")),
+                }
+            }
+            if !grid.is_empty() && row.len() != grid[0].len() {
+                return Err(ParseError::new(input, line, &format!("expected a row of {} trees", grid[0].len())));
+            }
+            grid.push(row);
+        }
+        if grid.is_empty() {
+            return Err(ParseError::new(input, input, "expected at least one row of trees"));
         }
 
         for (row, line) in grid.iter().enumerate() {
@@ -113,22 +136,26 @@ impl Forest {
         }
         let row_size: usize = grid[0].len();
         let col_size: usize = grid.len();
-        Forest { trees, row_size, col_size }
+        Ok(Forest { trees, row_size, col_size })
     }
 }
 
 
 impl Problem for DayEight {
-    fn part_one(&self, input: &str) -> String {
-        let forest = Forest::new(input);
+    type Input = Forest;
+
+    fn parse(&self, input: &str) -> Result<Forest, ProblemError> {
+        Ok(Forest::new(input)?)
+    }
+
+    fn part_one(&self, forest: &Forest) -> Result<Answer, ProblemError> {
         let visible_count: u32 = forest.num_visible();
-        format!("Visible trees: {visible_count}")
+        Ok(Answer::integer("Visible trees", visible_count))
     }
 
-    fn part_two(&self, input: &str) -> String {
-        let forest = Forest::new(input);
+    fn part_two(&self, forest: &Forest) -> Result<Answer, ProblemError> {
         let max_scenic: usize = forest.max_scenic_score();
-        format!("Max scenic score: {max_scenic}")
+        Ok(Answer::integer("Max scenic score", max_scenic))
     }
 }
 
@@ -137,14 +164,11 @@ impl Problem for DayEight {
 mod tests {
     use super::*;
 
+    const EXAMPLE: &str = include_str!("../../../examples/2022/day_8/example.txt");
+
     #[test]
     fn test_is_visible() {
-        let input="30373
-25512
-65332
-33549
-35390";
-        let forest = Forest::new(&input);
+        let forest = Forest::new(EXAMPLE).unwrap();
 
         // Outer trees
         assert_eq!(forest.is_visible(0, 0), true);
@@ -164,36 +188,10 @@ mod tests {
 
     #[test]
     fn test_scenic_score() {
-        let input="30373
-25512
-65332
-33549
-35390";
-        let forest = Forest::new(&input);
+        let forest = Forest::new(EXAMPLE).unwrap();
 
         // Outer trees
         assert_eq!(forest.scenic_score(1, 2), 4);
         assert_eq!(forest.scenic_score(3, 2), 8);
     }
-
-    #[test]
-    fn test_forest_p1() {
-        let input="30373
-25512
-65332
-33549
-35390";
-        assert_eq!(DayEight{}.part_one(&input), "Visible trees: 21");
-    }
-
-    #[test]
-    fn test_forest_p2() {
-        let input="30373
-25512
-65332
-33549
-35390";
-
-    assert_eq!(DayEight{}.part_two(&input), "Max scenic score: 8");
-    }
 }
@@ -1,10 +1,20 @@
-use crate::problem::Problem;
+use crate::parse::{self, ParseError};
+use crate::problem::{Answer, Problem, ProblemError, Registration};
 use std::collections::HashSet;
 use regex::Regex;
 
 
 pub struct DayNine {}
 
+pub const REGISTRATION: Registration = Registration {
+    year: 2022,
+    day: 9,
+    title: "Rope Bridge",
+    parts: &[1, 2],
+    params: &[],
+    new: |_, _| Box::new(DayNine{}),
+};
+
 #[derive(Debug, Eq, PartialEq, Clone)]
 enum Direction {
     Up,
@@ -14,7 +24,7 @@ enum Direction {
 }
 
 #[derive(Clone, Debug)]
-struct Move {
+pub struct Move {
     direction: Direction,
     count: u8
 }
@@ -85,26 +95,7 @@ impl Rope {
         }
     }
 
-    pub fn new(input: &str, knot_count:usize) -> Rope {
-        let mut head_moves = Vec::new();
-        for line in input.lines() {
-            lazy_static! {
-                static ref RE: Regex = Regex::new(r"^(?P<direction>[RLUD]+)\s(?P<count>[0-9]+)\s*$").unwrap();
-            }
-            
-            let cap = RE.captures(line.trim()).unwrap();
-            let direction_str: &str = cap.name("direction").unwrap().as_str();
-            let direction: Direction = match direction_str {
-                "R" => Direction::Right,
-                "L" => Direction::Left,
-                "U" => Direction::Up,
-                "D" => Direction::Down,
-                _ => panic!("Unknown direction {direction_str}")
-            };
-            let count: u8 = cap.name("count").unwrap().as_str().parse::<u8>().unwrap();
-
-            head_moves.push(Move { direction, count })
-        }
+    pub fn new(head_moves: Vec<Move>, knot_count:usize) -> Rope {
         let tail_visited = HashSet::from([(0,0)]);
         let mut knots = Vec::new();
         for _ in 0..knot_count {
@@ -115,20 +106,50 @@ impl Rope {
     }
 }
 
+fn parse(input: &str) -> Result<Vec<Move>, ParseError> {
+    let mut head_moves = Vec::new();
+    for line in input.lines() {
+        lazy_static! {
+            static ref RE: Regex = Regex::new(r"^(?P<direction>[RLUD]+)\s(?P<count>[0-9]+)\s*$").unwrap();
+        }
+        
+        let cap = parse::captures(input, &RE, line.trim(), "a direction and count")?;
+        let direction_str: &str = cap.name("direction").unwrap().as_str();
+        let direction: Direction = match direction_str {
+            "R" => Direction::Right,
+            "L" => Direction::Left,
+            "U" => Direction::Up,
+            "D" => Direction::Down,
+            _ => return Err(ParseError::new(input, direction_str, "unknown direction"))
+        };
+        let count: u8 = parse::capture(input, &cap, "count")?;
+
+        head_moves.push(Move { direction, count })
+    }
+
+    Ok(head_moves)
+}
+
 
 impl Problem for DayNine {
-    fn part_one(&self, input: &str) -> String {
-        let mut rope = Rope::new(input, 2);
+    type Input = Vec<Move>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Move>, ProblemError> {
+        Ok(parse(input)?)
+    }
+
+    fn part_one(&self, head_moves: &Vec<Move>) -> Result<Answer, ProblemError> {
+        let mut rope = Rope::new(head_moves.clone(), 2);
         rope.do_moves();
         let visited_count = rope.tail_visited.len();
-        format!("Number of positions visited by tail: {visited_count}")
+        Ok(Answer::integer("Number of positions visited by tail", visited_count))
     }
 
-    fn part_two(&self, input: &str) -> String {
-        let mut rope = Rope::new(input, 10);
+    fn part_two(&self, head_moves: &Vec<Move>) -> Result<Answer, ProblemError> {
+        let mut rope = Rope::new(head_moves.clone(), 10);
         rope.do_moves();
         let visited_count = rope.tail_visited.len();
-        format!("Number of positions visited by tail: {visited_count}")
+        Ok(Answer::integer("Number of positions visited by tail", visited_count))
     }
 }
 
@@ -137,17 +158,11 @@ impl Problem for DayNine {
 mod tests {
     use super::*;
 
+    const EXAMPLE: &str = include_str!("../../../examples/2022/day_9/example.txt");
+
     #[test]
     fn test_rope_init() {
-        let input="R 4
-U 4
-L 3
-D 1
-R 4
-D 1
-L 5
-R 2";
-        let rope = Rope::new(&input, 2);
+        let rope = Rope::new(parse(EXAMPLE).unwrap(), 2);
 
         assert_eq!(rope.knots[0], (0,0));
         assert_eq!(rope.knots[1], (0,0));
@@ -158,15 +173,7 @@ R 2";
 
     #[test]
     fn test_rope_single_move() {
-        let input="R 4
-U 4
-L 3
-D 1
-R 4
-D 1
-L 5
-R 2";
-        let mut rope = Rope::new(&input, 2);
+        let mut rope = Rope::new(parse(EXAMPLE).unwrap(), 2);
         rope.do_move(&Direction::Right);
         assert_eq!(rope.knots[0], (1,0));
         assert_eq!(rope.knots[1], (0,0));
@@ -175,46 +182,4 @@ R 2";
         assert_eq!(rope.knots[0], (2,0));
         assert_eq!(rope.knots[1], (1,0));
     }
-
-    #[test]
-    fn test_tail_visited_count_p1() {
-        let input="R 4
-U 4
-L 3
-D 1
-R 4
-D 1
-L 5
-R 2";
-        let output = DayNine{}.part_one(&input);
-        assert_eq!(output, "Number of positions visited by tail: 13")
-    }
-
-    #[test]
-    fn test_tail_visited_count_p2() {
-        let input="R 4
-U 4
-L 3
-D 1
-R 4
-D 1
-L 5
-R 2";
-        let output = DayNine{}.part_two(&input);
-        assert_eq!(output, "Number of positions visited by tail: 1")
-    }
-
-    #[test]
-    fn test_tail_visited_count_large_example_p2() {
-        let input="R 5
-U 8
-L 8
-D 3
-R 17
-D 10
-L 25
-U 20";
-        let output = DayNine{}.part_two(&input);
-        assert_eq!(output, "Number of positions visited by tail: 36")
-    }
 }
@@ -0,0 +1,38 @@
+use advent_of_code::{examples, years, Registration};
+use std::thread;
+
+const EXAMPLES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/examples");
+
+// Runs every registered day on each of its examples, in parallel, and reports
+// all the failures together.
+#[test]
+fn test_examples() {
+    let registrations: Vec<&Registration> = years::YEARS.iter().flat_map(|(_, registry)| registry.iter()).collect();
+    let failures: Vec<String> = thread::scope(|scope| {
+        let handles: Vec<_> = registrations.iter()
+            .map(|registration| scope.spawn(move || check_day(registration)))
+            .collect();
+        handles.into_iter().flat_map(|handle| handle.join().unwrap()).collect()
+    });
+    assert!(failures.is_empty(), "{} example failure(s):\n{}", failures.len(), failures.join("\n"));
+}
+
+fn check_day(registration: &Registration) -> Vec<String> {
+    let label = format!("{} day {}", registration.year, registration.day);
+    let examples = match examples::find(EXAMPLES_DIR, registration.year, registration.day) {
+        Ok(examples) => examples,
+        Err(e) => return vec![format!("{label}: {e}")],
+    };
+    if examples.is_empty() {
+        return vec![format!("{label}: no examples in {}", examples::day_dir(EXAMPLES_DIR, registration.year, registration.day))];
+    }
+
+    let mut failures = Vec::new();
+    for example in &examples {
+        match examples::check(registration, example) {
+            Ok(example_failures) => failures.extend(example_failures.iter().map(|failure| format!("{label} {}: {failure}", example.name))),
+            Err(e) => failures.push(format!("{label} {}: {e}", example.name)),
+        }
+    }
+    failures
+}
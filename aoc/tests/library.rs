@@ -0,0 +1,51 @@
+use advent_of_code::{Graph, Problem, ProblemError, years};
+use advent_of_code::problem::Value;
+use advent_of_code::years::y2022::day_12::DayTwelve;
+
+#[test]
+fn test_solver_from_library() {
+    let input = "Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi";
+    let map = DayTwelve::default().parse(input).unwrap();
+    assert_eq!(DayTwelve::default().part_one(&map).unwrap().value, Value::Integer(31));
+}
+
+#[test]
+fn test_registry_from_library() {
+    let registration = years::get(2022, 1).unwrap();
+    let solver = registration.problem();
+    let parsed = solver.parse_input("1000\n2000\n\n4000\n").unwrap();
+    assert_eq!(solver.solve(1, parsed.as_ref()).unwrap().to_string(), "Maximum elf score: 4000");
+}
+
+#[test]
+fn test_parse_error_from_library() {
+    let registration = years::get(2022, 1).unwrap();
+    match registration.problem().parse_input("1000\nabc\n") {
+        Err(ProblemError::Parse(error)) => assert_eq!((error.line, error.column), (2, 1)),
+        result => panic!("Expected a parse error, got {result:?}"),
+    }
+}
+
+#[test]
+fn test_unsolved_part_from_library() {
+    let solver = years::get(2022, 25).unwrap().problem();
+    let parsed = solver.parse_input("1=-0-2\n12111\n").unwrap();
+    assert!(solver.solve(1, parsed.as_ref()).is_ok());
+    assert_eq!(solver.solve(2, parsed.as_ref()), Err(ProblemError::Unimplemented));
+}
+
+#[test]
+fn test_graph_from_library() {
+    let mut graph = Graph::new();
+    let a = graph.add_node();
+    let b = graph.add_node();
+    let c = graph.add_node();
+    graph.add_edge(a, b);
+    graph.add_edge(b, c);
+    assert_eq!(graph.bfs(a, c), Some(2));
+    assert_eq!(graph.bfs(c, a), None);
+}